@@ -0,0 +1,519 @@
+//! 오버레이 알림 큐
+//!
+//! 한 번의 폴링에서 여러 팔로워가 감지되어도 오버레이 알림이 겹치지 않도록 서버가 하나씩 내보냅니다.
+//! `alerts` 토픽을 구독한 오버레이가 있을 때만 보내며, 구독자가 `alert_done { id }`로 표시가 끝났음을 알리면 다음 알림을 보내고, 응답이 없으면
+//! `displayDuration`에 여유 시간을 더한 만큼 기다린 뒤 넘어갑니다. 그 사이 오버레이가 모두 끊겼으면 알림을 다시 대기열 맨 앞에 넣습니다.
+//! 오버레이가 없는 동안 대기열이 끝없이 쌓이지 않도록 개수와 대기 시간에 제한을 둡니다.
+//! 일시정지/재개/건너뛰기/다시 재생은 WebSocket(`alert_control`)과 HTTP(`/alerts/*`)로 제공합니다.
+
+use crate::chzzk::FollowerItem;
+use crate::state::AppState;
+use crate::watchlist::WatchAlert;
+use crate::websocket::WSManager;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::AppHandle;
+use tokio::sync::Notify;
+
+/// 오버레이 응답이 없을 때 `displayDuration`에 더해 기다리는 시간
+const ACK_GRACE: Duration = Duration::from_secs(3);
+
+/// 설정이 없을 때의 알림 표시 시간 (초, 설정 화면 기본값과 동일)
+const DEFAULT_DISPLAY_SECS: f64 = 5.0;
+
+/// 다시 재생할 수 있도록 보관하는 최근 알림 수
+pub const MAX_RECENT_ALERTS: usize = 20;
+
+/// 대기열에 쌓아 두는 최대 알림 수 (넘으면 오래된 알림부터 버림)
+pub const MAX_PENDING_ALERTS: usize = 50;
+
+/// 대기열에서 기다릴 수 있는 최대 시간 (초, 넘은 알림은 표시하지 않고 버림)
+const MAX_PENDING_AGE_SECS: i64 = 600;
+
+/// 알림을 받는 WebSocket 토픽
+const ALERTS_TOPIC: &str = "alerts";
+
+/// 큐에 들어가는 알림 하나
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Alert {
+    pub id: u64,
+    pub follower: FollowerItem,
+    // 관심 팔로워 전용 사운드/문구
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert: Option<WatchAlert>,
+    #[serde(rename = "isRefollow", default)]
+    pub is_refollow: bool,
+    #[serde(rename = "isTest", default)]
+    pub is_test: bool,
+    // 다시 재생 요청으로 들어온 알림
+    #[serde(default)]
+    pub replay: bool,
+    #[serde(rename = "queuedAt")]
+    pub queued_at: String,
+}
+
+/// 큐 상태 (GET /alerts, `alert_queue` 메시지)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AlertQueueStatus {
+    pub paused: bool,
+    /// 표시 중인 알림
+    pub current: Option<Alert>,
+    /// 대기 중인 알림 (먼저 표시할 것부터)
+    pub pending: Vec<Alert>,
+    /// 표시가 끝난 알림 (최신순, 최대 20개)
+    pub recent: Vec<Alert>,
+}
+
+/// 큐 제어 명령
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertAction {
+    Pause,
+    Resume,
+    Skip,
+    Replay,
+}
+
+/// POST /alerts/replay 요청 본문 (id가 없으면 마지막 알림)
+#[derive(Debug, Default, Deserialize)]
+pub struct AlertReplayRequest {
+    pub id: Option<u64>,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    next_id: u64,
+    paused: bool,
+    current: Option<Alert>,
+    pending: VecDeque<Alert>,
+    recent: VecDeque<Alert>,
+}
+
+impl QueueState {
+    fn push(&mut self, mut alert: Alert, front: bool) -> u64 {
+        self.next_id += 1;
+        alert.id = self.next_id;
+        if front {
+            self.pending.push_front(alert);
+        } else {
+            self.pending.push_back(alert);
+        }
+
+        // 가장 오래 기다린 알림부터 버림 (직접 요청한 다시 재생은 남김)
+        while self.pending.len() > MAX_PENDING_ALERTS {
+            let Some(index) = self.pending.iter().position(|alert| !alert.replay) else {
+                break;
+            };
+            if let Some(dropped) = self.pending.remove(index) {
+                log::warn!("[AlertQueue] 대기열이 가득 차 알림 #{} 버림", dropped.id);
+            }
+        }
+        self.next_id
+    }
+
+    // 너무 오래 기다린 알림 버림 (queuedAt을 알 수 없으면 남김)
+    fn drop_expired(&mut self, now: chrono::DateTime<chrono::Utc>) {
+        let max_age = chrono::Duration::seconds(MAX_PENDING_AGE_SECS);
+        self.pending.retain(|alert| {
+            let expired = chrono::DateTime::parse_from_rfc3339(&alert.queued_at)
+                .is_ok_and(|queued_at| now.signed_duration_since(queued_at) > max_age);
+            if expired {
+                log::warn!("[AlertQueue] 알림 #{}이 너무 오래 기다려 버림", alert.id);
+            }
+            !expired
+        });
+    }
+
+    // 표시 중인 알림이 id면 끝난 것으로 옮김
+    fn finish(&mut self, id: u64) -> bool {
+        if self.current.as_ref().is_none_or(|alert| alert.id != id) {
+            return false;
+        }
+        if let Some(alert) = self.current.take() {
+            self.recent.push_front(alert);
+            self.recent.truncate(MAX_RECENT_ALERTS);
+        }
+        true
+    }
+}
+
+/// AppState에 보관하는 알림 큐 (전송은 `spawn_dispatcher` 태스크가 담당)
+#[derive(Default)]
+pub struct AlertQueue {
+    state: Mutex<QueueState>,
+    changed: Notify,
+}
+
+impl AlertQueue {
+    /// 알림 추가, 새 알림 id 반환
+    pub fn enqueue(
+        &self,
+        follower: FollowerItem,
+        alert: Option<WatchAlert>,
+        is_refollow: bool,
+        is_test: bool,
+    ) -> Option<u64> {
+        let alert = Alert {
+            id: 0,
+            follower,
+            alert,
+            is_refollow,
+            is_test,
+            replay: false,
+            queued_at: chrono::Utc::now().to_rfc3339(),
+        };
+        let id = self.state.lock().ok()?.push(alert, false);
+        self.changed.notify_one();
+        Some(id)
+    }
+
+    /// 현재 상태
+    pub fn status(&self) -> AlertQueueStatus {
+        let Ok(state) = self.state.lock() else {
+            return AlertQueueStatus::default();
+        };
+        AlertQueueStatus {
+            paused: state.paused,
+            current: state.current.clone(),
+            pending: state.pending.iter().cloned().collect(),
+            recent: state.recent.iter().cloned().collect(),
+        }
+    }
+
+    /// 오버레이의 표시 완료 응답 (표시 중인 알림이 아니면 무시)
+    pub fn done(&self, id: u64) -> bool {
+        let finished = self.state.lock().map(|mut state| state.finish(id)).unwrap_or(false);
+        if finished {
+            self.changed.notify_one();
+        }
+        finished
+    }
+
+    /// 일시정지 (표시 중인 알림은 끝까지 표시하고 다음 알림부터 멈춤)
+    pub fn set_paused(&self, paused: bool) {
+        if let Ok(mut state) = self.state.lock() {
+            state.paused = paused;
+        }
+        self.changed.notify_one();
+    }
+
+    /// 표시 중인 알림 건너뛰기, 건너뛴 알림 id 반환
+    pub fn skip(&self) -> Option<u64> {
+        let mut state = self.state.lock().ok()?;
+        let id = state.current.as_ref()?.id;
+        state.finish(id);
+        drop(state);
+        self.changed.notify_one();
+        Some(id)
+    }
+
+    /// 끝난 알림을 다시 재생 (id가 없으면 마지막 알림), 맨 앞에 넣고 새 id 반환
+    pub fn replay(&self, id: Option<u64>) -> Result<u64, String> {
+        let mut state = self.state.lock().map_err(|e| e.to_string())?;
+        let alert = match id {
+            Some(id) => state.recent.iter().find(|alert| alert.id == id),
+            None => state.recent.front(),
+        }
+        .cloned()
+        .ok_or_else(|| "다시 재생할 알림이 없습니다".to_string())?;
+
+        let new_id = state.push(
+            Alert {
+                replay: true,
+                queued_at: chrono::Utc::now().to_rfc3339(),
+                ..alert
+            },
+            true,
+        );
+        drop(state);
+        self.changed.notify_one();
+        Ok(new_id)
+    }
+
+    /// 표시할 수 있으면 다음 알림을 꺼내 표시 중으로 옮김
+    pub(crate) fn take_next(&self) -> Option<Alert> {
+        let mut state = self.state.lock().ok()?;
+        if state.paused || state.current.is_some() {
+            return None;
+        }
+        state.drop_expired(chrono::Utc::now());
+        let alert = state.pending.pop_front()?;
+        state.current = Some(alert.clone());
+        Some(alert)
+    }
+
+    // 표시 중인 알림이 id면 같은 id로 대기열 맨 앞에 되돌림 (받을 오버레이가 없을 때)
+    fn requeue(&self, id: u64) -> bool {
+        let Ok(mut state) = self.state.lock() else {
+            return false;
+        };
+        if state.current.as_ref().is_none_or(|alert| alert.id != id) {
+            return false;
+        }
+        if let Some(alert) = state.current.take() {
+            state.pending.push_front(alert);
+        }
+        true
+    }
+
+    /// 표시 중(완료 응답 전)인 알림인지
+    pub fn is_current(&self, id: u64) -> bool {
+        self.state
+            .lock()
+            .map(|state| state.current.as_ref().is_some_and(|alert| alert.id == id))
+            .unwrap_or(false)
+    }
+}
+
+/// HTTP/WebSocket 공통 제어 처리 (건너뛴 알림은 오버레이가 바로 닫도록 브로드캐스트)
+pub async fn control(
+    app_state: &AppState,
+    ws_manager: &WSManager,
+    action: AlertAction,
+    id: Option<u64>,
+) -> Result<AlertQueueStatus, String> {
+    let alerts = &app_state.alerts;
+
+    match action {
+        AlertAction::Pause => alerts.set_paused(true),
+        AlertAction::Resume => alerts.set_paused(false),
+        AlertAction::Skip => {
+            let skipped = alerts.skip().ok_or_else(|| "표시 중인 알림이 없습니다".to_string())?;
+            ws_manager.broadcast_alert_skipped(skipped).await;
+        }
+        AlertAction::Replay => {
+            alerts.replay(id)?;
+        }
+    }
+
+    log::info!("[AlertQueue] {:?} 처리", action);
+    Ok(alerts.status())
+}
+
+// 응답이 없을 때 기다리는 시간 (displayDuration + 여유)
+fn ack_timeout(app_handle: &AppHandle) -> Duration {
+    let display_secs = crate::server::read_settings(app_handle)
+        .get("displayDuration")
+        .and_then(serde_json::Value::as_f64)
+        .filter(|secs| secs.is_finite() && *secs > 0.0)
+        .unwrap_or(DEFAULT_DISPLAY_SECS);
+
+    Duration::from_secs_f64(display_secs) + ACK_GRACE
+}
+
+/// 알림을 하나씩 내보내는 태스크 시작
+pub fn spawn_dispatcher(app_state: Arc<AppState>, ws_manager: WSManager, app_handle: AppHandle) {
+    tokio::spawn(run_dispatcher(app_state, ws_manager, move || ack_timeout(&app_handle)));
+
+    log::info!("[AlertQueue] Dispatcher started");
+}
+
+// 알림을 하나씩 내보내는 루프 (ack_timeout: 완료 응답을 기다리는 시간)
+async fn run_dispatcher(app_state: Arc<AppState>, ws_manager: WSManager, ack_timeout: impl Fn() -> Duration) {
+    let alerts = &app_state.alerts;
+
+    loop {
+        // 받을 오버레이(`alerts` 구독자)가 생길 때까지 큐에 그대로 둠
+        ws_manager.wait_for_subscriber(ALERTS_TOPIC).await;
+
+        let Some(alert) = alerts.take_next() else {
+            alerts.changed.notified().await;
+            continue;
+        };
+
+        // 새 알림을 기다리는 동안 오버레이가 모두 끊겼으면 되돌림
+        let id = alert.id;
+        if ws_manager.subscriber_count(ALERTS_TOPIC).await == 0 {
+            alerts.requeue(id);
+            continue;
+        }
+
+        log::info!("[AlertQueue] 알림 #{} 표시: {}", id, alert.follower.user.nickname);
+        ws_manager.broadcast_alert(alert).await;
+
+        // 완료 응답, 건너뛰기, 시간 초과 중 먼저 오는 것까지 대기
+        let deadline = tokio::time::Instant::now() + ack_timeout();
+        loop {
+            tokio::select! {
+                () = tokio::time::sleep_until(deadline) => {
+                    if ws_manager.subscriber_count(ALERTS_TOPIC).await == 0 {
+                        // 표시 중에 오버레이가 끊김, 다음 오버레이에 다시 보냄
+                        if alerts.requeue(id) {
+                            log::info!("[AlertQueue] 알림 #{}을 받을 오버레이가 없어 다시 대기", id);
+                        }
+                    } else if alerts.done(id) {
+                        log::warn!("[AlertQueue] 알림 #{} 완료 응답 없음, 다음 알림으로 넘어감", id);
+                    }
+                    break;
+                }
+                () = alerts.changed.notified() => {
+                    if !alerts.is_current(id) {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chzzk::test_support::follower;
+
+    #[test]
+    fn dispatches_one_alert_at_a_time() {
+        let queue = AlertQueue::default();
+        let a = queue.enqueue(follower("a", "a"), None, false, false).unwrap();
+        let b = queue.enqueue(follower("b", "b"), None, false, false).unwrap();
+
+        assert_eq!(queue.take_next().map(|alert| alert.id), Some(a));
+        // 완료 응답 전에는 다음 알림을 꺼내지 않음
+        assert!(queue.take_next().is_none());
+        // 표시 중이 아닌 알림의 응답은 무시
+        assert!(!queue.done(b));
+        assert!(queue.done(a));
+        assert_eq!(queue.take_next().map(|alert| alert.id), Some(b));
+    }
+
+    #[test]
+    fn pause_holds_pending_alerts() {
+        let queue = AlertQueue::default();
+        queue.set_paused(true);
+        queue.enqueue(follower("a", "a"), None, false, false);
+
+        assert!(queue.take_next().is_none());
+        assert_eq!(queue.status().pending.len(), 1);
+
+        queue.set_paused(false);
+        assert!(queue.take_next().is_some());
+    }
+
+    #[test]
+    fn skipped_alert_can_be_replayed_next() {
+        let queue = AlertQueue::default();
+        let a = queue.enqueue(follower("a", "a"), None, false, false).unwrap();
+        queue.enqueue(follower("b", "b"), None, false, false);
+        queue.take_next();
+
+        assert_eq!(queue.skip(), Some(a));
+        assert!(queue.skip().is_none());
+
+        let replayed = queue.replay(None).unwrap();
+        let next = queue.take_next().unwrap();
+        assert_eq!(next.id, replayed);
+        assert_eq!(next.follower.user.nickname, "a");
+        assert!(next.replay);
+        assert!(queue.replay(Some(999)).is_err());
+    }
+
+    #[test]
+    fn pending_alerts_are_capped_and_expire() {
+        let queue = AlertQueue::default();
+        let first = queue.enqueue(follower("first", "first"), None, false, false).unwrap();
+        queue.take_next();
+        queue.done(first);
+        let replayed = queue.replay(None).unwrap();
+        for i in 0..MAX_PENDING_ALERTS + 5 {
+            let name = i.to_string();
+            queue.enqueue(follower(&name, &name), None, false, false);
+        }
+
+        // 넘친 만큼 오래된 알림부터 버리고 다시 재생은 남김
+        let pending = queue.status().pending;
+        assert_eq!(pending.len(), MAX_PENDING_ALERTS);
+        assert_eq!(pending[0].id, replayed);
+        assert_eq!(pending[1].follower.user.nickname, "6");
+
+        let mut state = queue.state.lock().unwrap();
+        state.pending[1].queued_at = "2024-01-01T00:00:00Z".to_string();
+        state.pending[2].queued_at = "not a time".to_string();
+        state.drop_expired(chrono::Utc::now());
+        assert_eq!(state.pending.len(), MAX_PENDING_ALERTS - 1);
+        assert_eq!(state.pending[1].follower.user.nickname, "7");
+    }
+
+    // 디스패처가 보낸 다음 알림 (제한 시간 안에 없으면 None)
+    async fn next_alert(rx: &mut tokio::sync::broadcast::Receiver<crate::websocket::WSEvent>) -> Option<Alert> {
+        loop {
+            let event = tokio::time::timeout(Duration::from_millis(500), rx.recv()).await.ok()?.ok()?;
+            if let crate::websocket::WSMessage::Alert { alert } = event.message {
+                return Some(alert);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatcher_waits_for_alert_subscribers() {
+        let app_state = Arc::new(AppState::default());
+        let ws_manager = WSManager::new();
+        let mut rx = ws_manager.subscribe();
+        tokio::spawn(run_dispatcher(Arc::clone(&app_state), ws_manager.clone(), || Duration::from_secs(60)));
+
+        // followers만 구독한 대시보드로는 알림을 내보내지 않음
+        let mut dashboard = crate::websocket::WSClient::new("dashboard".to_string());
+        dashboard.subscribe(vec!["followers".to_string()]);
+        ws_manager.add_client(dashboard).await.unwrap();
+        let id = app_state.alerts.enqueue(follower("a", "a"), None, false, false).unwrap();
+        assert!(next_alert(&mut rx).await.is_none());
+        assert_eq!(app_state.alerts.status().pending.len(), 1);
+
+        ws_manager
+            .add_client(crate::websocket::WSClient::new("overlay".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(next_alert(&mut rx).await.map(|alert| alert.id), Some(id));
+        assert!(app_state.alerts.is_current(id));
+    }
+
+    #[tokio::test]
+    async fn dispatcher_moves_on_after_ack_timeout() {
+        let app_state = Arc::new(AppState::default());
+        let ws_manager = WSManager::new();
+        let mut rx = ws_manager.subscribe();
+        ws_manager
+            .add_client(crate::websocket::WSClient::new("overlay".to_string()))
+            .await
+            .unwrap();
+        tokio::spawn(run_dispatcher(Arc::clone(&app_state), ws_manager.clone(), || Duration::from_millis(50)));
+
+        let a = app_state.alerts.enqueue(follower("a", "a"), None, false, false).unwrap();
+        let b = app_state.alerts.enqueue(follower("b", "b"), None, false, false).unwrap();
+
+        // 완료 응답이 없어도 시간이 지나면 다음 알림
+        assert_eq!(next_alert(&mut rx).await.map(|alert| alert.id), Some(a));
+        assert_eq!(next_alert(&mut rx).await.map(|alert| alert.id), Some(b));
+        assert_eq!(app_state.alerts.status().recent[0].id, a);
+    }
+
+    #[tokio::test]
+    async fn dispatcher_requeues_alert_when_overlay_disconnects() {
+        let app_state = Arc::new(AppState::default());
+        let ws_manager = WSManager::new();
+        let mut rx = ws_manager.subscribe();
+        ws_manager
+            .add_client(crate::websocket::WSClient::new("overlay".to_string()))
+            .await
+            .unwrap();
+        tokio::spawn(run_dispatcher(Arc::clone(&app_state), ws_manager.clone(), || Duration::from_millis(50)));
+
+        let id = app_state.alerts.enqueue(follower("a", "a"), None, false, false).unwrap();
+        assert_eq!(next_alert(&mut rx).await.map(|alert| alert.id), Some(id));
+        ws_manager.remove_client("overlay").await;
+
+        // 시간 초과 때 받을 오버레이가 없으면 버리지 않고 다시 대기
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        let status = app_state.alerts.status();
+        assert!(status.current.is_none());
+        assert_eq!(status.pending.first().map(|alert| alert.id), Some(id));
+        assert!(status.recent.is_empty());
+
+        ws_manager
+            .add_client(crate::websocket::WSClient::new("overlay-2".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(next_alert(&mut rx).await.map(|alert| alert.id), Some(id));
+    }
+}
@@ -0,0 +1,212 @@
+//! 팔로워 기준점(baseline) 저장
+//!
+//! 마지막으로 확인한 팔로워 목록(userIdHash + followingSince)을 앱 데이터 폴더에 저장해 두었다가
+//! 재시작 시 불러옵니다. 앱이 꺼져 있는 동안 들어온 팔로우를 알릴지, 조용히 기준점에 반영할지는
+//! `offlineFollowMode` 설정으로 정합니다.
+
+use crate::chzzk::{FollowerItem, User};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+/// 앱 데이터 폴더 안의 기준점 파일 이름
+const BASELINE_FILE: &str = "follower_baseline.json";
+
+/// 파일 형식 버전 (형식이 바뀌면 이전 파일은 무시)
+const BASELINE_VERSION: u32 = 1;
+
+/// 앱이 꺼져 있는 동안 들어온 팔로우 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OfflineFollowMode {
+    /// 재시작 후 첫 조회에서 알림으로 표시
+    #[default]
+    Announce,
+    /// 알림 없이 기준점에 반영
+    Absorb,
+}
+
+impl OfflineFollowMode {
+    pub fn from_setting(value: &serde_json::Value) -> Self {
+        match value.as_str() {
+            Some("absorb") => Self::Absorb,
+            _ => Self::Announce,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    #[serde(rename = "userIdHash")]
+    pub user_id_hash: String,
+    pub nickname: String,
+    #[serde(rename = "followingSince")]
+    pub following_since: String,
+}
+
+/// 디스크에 저장하는 기준점
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FollowerBaseline {
+    pub version: u32,
+    #[serde(rename = "channelId")]
+    pub channel_id: String,
+    #[serde(rename = "savedAt")]
+    pub saved_at: String,
+    pub followers: Vec<BaselineEntry>,
+}
+
+impl FollowerBaseline {
+    pub fn new(channel_id: &str, followers: &[FollowerItem]) -> Self {
+        Self {
+            version: BASELINE_VERSION,
+            channel_id: channel_id.to_string(),
+            saved_at: chrono::Utc::now().to_rfc3339(),
+            followers: followers
+                .iter()
+                .map(|f| BaselineEntry {
+                    user_id_hash: f.user.user_id_hash.clone(),
+                    nickname: f.user.nickname.clone(),
+                    following_since: f.following_since.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// 비교에 쓸 팔로워 목록으로 변환 (프로필 이미지는 저장하지 않음)
+    pub fn into_followers(self) -> Vec<FollowerItem> {
+        self.followers
+            .into_iter()
+            .map(|entry| FollowerItem {
+                user: User {
+                    user_id_hash: entry.user_id_hash,
+                    nickname: entry.nickname,
+                    profile_image_url: None,
+                },
+                following_since: entry.following_since,
+            })
+            .collect()
+    }
+}
+
+/// 기준점 파일 경로 (앱 데이터 폴더)
+pub fn baseline_path(app_handle: &AppHandle) -> Option<PathBuf> {
+    match app_handle.path().app_data_dir() {
+        Ok(dir) => Some(dir.join(BASELINE_FILE)),
+        Err(e) => {
+            log::error!("[Baseline] 앱 데이터 폴더를 찾을 수 없습니다: {}", e);
+            None
+        }
+    }
+}
+
+/// 저장된 기준점 불러오기 (다른 채널이거나 형식이 맞지 않으면 무시)
+pub fn load(path: &Path, channel_id: &str) -> Option<FollowerBaseline> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(e) => {
+            log::warn!("[Baseline] 기준점 파일 읽기 실패: {}", e);
+            return None;
+        }
+    };
+
+    let baseline: FollowerBaseline = match serde_json::from_str(&content) {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            log::warn!("[Baseline] 기준점 파일 형식 오류, 무시합니다: {}", e);
+            return None;
+        }
+    };
+
+    if baseline.version != BASELINE_VERSION {
+        log::warn!("[Baseline] 지원하지 않는 기준점 버전 {}, 무시합니다", baseline.version);
+        return None;
+    }
+    if baseline.channel_id != channel_id {
+        log::info!("[Baseline] 다른 채널의 기준점이므로 무시합니다");
+        return None;
+    }
+
+    Some(baseline)
+}
+
+/// 기준점 저장 (임시 파일에 쓴 뒤 교체해서 중간에 꺼져도 파일이 깨지지 않음)
+pub fn save(path: &Path, baseline: &FollowerBaseline) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("폴더 생성 실패: {}", e))?;
+    }
+
+    let content = serde_json::to_string(baseline).map_err(|e| format!("직렬화 실패: {}", e))?;
+    let tmp_path = path.with_extension("json.tmp");
+
+    std::fs::write(&tmp_path, content).map_err(|e| format!("임시 파일 쓰기 실패: {}", e))?;
+    std::fs::rename(&tmp_path, path).map_err(|e| format!("파일 교체 실패: {}", e))?;
+
+    Ok(())
+}
+
+/// `offlineFollowMode` 설정 읽기
+pub fn read_offline_follow_mode(app_handle: &AppHandle) -> OfflineFollowMode {
+    use tauri_plugin_store::StoreExt;
+
+    app_handle
+        .store("settings.json")
+        .ok()
+        .and_then(|store| store.get("offlineFollowMode"))
+        .map(|value| OfflineFollowMode::from_setting(&value))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chzzk::test_support::follower;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("fazzk-baseline-{}-{}", name, std::process::id()))
+            .join(BASELINE_FILE)
+    }
+
+    #[test]
+    fn saved_baseline_round_trips() {
+        let path = temp_path("round-trip");
+        let mut b = follower("b", "b-nick");
+        b.user.profile_image_url = Some("https://example.com/a.png".to_string());
+        save(&path, &FollowerBaseline::new("channel", &[b, follower("a", "a-nick")])).unwrap();
+
+        let followers = load(&path, "channel").unwrap().into_followers();
+
+        assert_eq!(followers.len(), 2);
+        assert_eq!(followers[0].user.user_id_hash, "b");
+        assert_eq!(followers[0].user.nickname, "b-nick");
+        assert_eq!(followers[0].following_since, "2024-01-01 09:00:00");
+        // 프로필 이미지는 저장하지 않음
+        assert!(followers[0].user.profile_image_url.is_none());
+        assert!(!path.with_extension("json.tmp").exists());
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn ignores_other_channel_and_version() {
+        let path = temp_path("mismatch");
+        let mut baseline = FollowerBaseline::new("channel", &[follower("a", "a-nick")]);
+        save(&path, &baseline).unwrap();
+        assert!(load(&path, "other-channel").is_none());
+
+        baseline.version = BASELINE_VERSION + 1;
+        save(&path, &baseline).unwrap();
+        assert!(load(&path, "channel").is_none());
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn ignores_missing_and_corrupt_files() {
+        let path = temp_path("corrupt");
+        assert!(load(&path, "channel").is_none());
+
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "{ not json").unwrap();
+        assert!(load(&path, "channel").is_none());
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -0,0 +1,408 @@
+//! 개발 및 통합 테스트용 치지직 목(mock) 서버
+//!
+//! `getUserStatus`와 팔로워 목록 API를 흉내 내고, 작은 REST API로 동작을 조작합니다.
+//! 앱을 목 서버에 연결하려면 두 API 주소를 환경 변수로 덮어씁니다.
+//!
+//! ```text
+//! fazzk-mock-chzzk --port 3900
+//! FAZZK_CHZZK_API_URL=http://127.0.0.1:3900 FAZZK_GAME_API_URL=http://127.0.0.1:3900 npm run tauri dev
+//! ```
+//!
+//! 조작 API (`/mock` 아래):
+//! - `GET /mock/state`: 현재 팔로워, 장애 모드, 지연 시간 조회
+//! - `POST /mock/followers`: 팔로워 추가 (`{ "nickname": "...", "userIdHash": "..." }`)
+//! - `POST /mock/followers/burst`: 팔로워 여러 명 추가 (`{ "count": 30 }`)
+//! - `DELETE /mock/followers/{userIdHash}`: 팔로워 제거 (언팔로우)
+//! - `POST /mock/fault`: 장애 모드 설정 (`{ "mode": "unauthorized" | "malformed" | "server_error" | "rate_limited" | "none", "count": 3 }`)
+//! - `POST /mock/latency`: 응답 지연 설정 (`{ "ms": 2000 }`)
+//! - `POST /mock/reset`: 초기 상태로 되돌리기
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{delete, get, post},
+    Json, Router,
+};
+use fazzk_tauri_lib::chzzk::{FollowerItem, User, API_BASE_URL_ENV, GAME_API_BASE_URL_ENV};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+/// 기본 포트 (`--port` 또는 `FAZZK_MOCK_PORT`로 변경)
+const DEFAULT_PORT: u16 = 3900;
+
+/// 목 채널 userIdHash
+const CHANNEL_ID: &str = "mock_channel";
+
+/// 목 채널 닉네임
+const CHANNEL_NICKNAME: &str = "목 스트리머";
+
+/// 초기 팔로워 수
+const INITIAL_FOLLOWERS: usize = 15;
+
+/// 요청 한도 초과 응답의 Retry-After (초)
+const RATE_LIMIT_RETRY_AFTER_SECS: u64 = 1;
+
+// 장애 모드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum FaultMode {
+    None,
+    Unauthorized,
+    Malformed,
+    ServerError,
+    RateLimited,
+}
+
+#[derive(Debug, Serialize)]
+struct MockState {
+    followers: Vec<FollowerItem>, // 최신 팔로우 순
+    fault: FaultMode,
+    // 장애를 돌려줄 남은 요청 수 (None이면 해제할 때까지 계속)
+    fault_remaining: Option<u32>,
+    latency_ms: u64,
+    request_count: u64,
+    next_id: u64,
+}
+
+impl MockState {
+    fn new() -> Self {
+        let mut state = Self {
+            followers: Vec::new(),
+            fault: FaultMode::None,
+            fault_remaining: None,
+            latency_ms: 0,
+            request_count: 0,
+            next_id: 0,
+        };
+
+        // 오래된 팔로워부터 하루 간격으로 채우기
+        let now = chrono::Utc::now();
+        for i in 0..INITIAL_FOLLOWERS {
+            let since = now - chrono::Duration::days((INITIAL_FOLLOWERS - i) as i64);
+            let follower = state.make_follower(None, None, since);
+            state.followers.insert(0, follower);
+        }
+        state
+    }
+
+    fn make_follower(
+        &mut self,
+        user_id_hash: Option<String>,
+        nickname: Option<String>,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> FollowerItem {
+        self.next_id += 1;
+        FollowerItem {
+            user: User {
+                user_id_hash: user_id_hash.unwrap_or_else(|| format!("mock_user_{}", self.next_id)),
+                nickname: nickname.unwrap_or_else(|| format!("팔로워{}", self.next_id)),
+                profile_image_url: None,
+            },
+            following_since: format_following_since(since),
+        }
+    }
+
+    fn follow(&mut self, user_id_hash: Option<String>, nickname: Option<String>) -> FollowerItem {
+        // 같은 사용자가 다시 팔로우하면 기존 항목을 지우고 맨 위에 추가
+        if let Some(hash) = &user_id_hash {
+            self.followers.retain(|f| &f.user.user_id_hash != hash);
+        }
+
+        // 같은 초에 여러 명이 팔로우해도 순서가 유지되도록 직전 팔로우보다 늦은 시각 사용 (followingSince는 초 단위)
+        let mut since = chrono::Utc::now();
+        if let Some(latest) = self.followers.first().and_then(FollowerItem::followed_at) {
+            if since.timestamp() <= latest.timestamp() {
+                since = latest + chrono::Duration::seconds(1);
+            }
+        }
+
+        let follower = self.make_follower(user_id_hash, nickname, since);
+        self.followers.insert(0, follower.clone());
+        follower
+    }
+
+    // 이번 요청에 적용할 장애 모드
+    fn take_fault(&mut self) -> FaultMode {
+        if self.fault == FaultMode::None {
+            return FaultMode::None;
+        }
+
+        let fault = self.fault;
+        if let Some(remaining) = self.fault_remaining.as_mut() {
+            *remaining = remaining.saturating_sub(1);
+            if *remaining == 0 {
+                self.fault = FaultMode::None;
+                self.fault_remaining = None;
+            }
+        }
+        fault
+    }
+}
+
+type SharedState = Arc<Mutex<MockState>>;
+
+// 치지직과 같은 KST 기준 "YYYY-MM-DD HH:MM:SS" 형식
+fn format_following_since(time: chrono::DateTime<chrono::Utc>) -> String {
+    let kst = chrono::FixedOffset::east_opt(9 * 3600).expect("valid offset");
+    time.with_timezone(&kst).format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+// 지연 적용 후 장애 모드에 맞는 응답 반환 (정상이면 None)
+async fn apply_behaviour(state: &SharedState) -> Option<Response> {
+    let (latency_ms, fault) = {
+        let mut state = state.lock().unwrap();
+        state.request_count += 1;
+        (state.latency_ms, state.take_fault())
+    };
+
+    if latency_ms > 0 {
+        tokio::time::sleep(Duration::from_millis(latency_ms)).await;
+    }
+
+    match fault {
+        FaultMode::None => None,
+        FaultMode::Unauthorized => Some(
+            (
+                StatusCode::UNAUTHORIZED,
+                Json(json!({ "code": 401, "message": "로그인이 필요합니다.", "content": null })),
+            )
+                .into_response(),
+        ),
+        FaultMode::Malformed => Some(
+            (
+                StatusCode::OK,
+                [("content-type", "application/json")],
+                r#"{"code":200,"content":{"data":[{"user":"#,
+            )
+                .into_response(),
+        ),
+        FaultMode::ServerError => Some(
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "code": 500, "message": "Internal Server Error", "content": null })),
+            )
+                .into_response(),
+        ),
+        FaultMode::RateLimited => Some(
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [("retry-after", RATE_LIMIT_RETRY_AFTER_SECS.to_string())],
+                Json(json!({ "code": 429, "message": "Too Many Requests", "content": null })),
+            )
+                .into_response(),
+        ),
+    }
+}
+
+// GET /nng_main/v1/user/getUserStatus
+async fn get_user_status(State(state): State<SharedState>) -> Response {
+    if let Some(response) = apply_behaviour(&state).await {
+        return response;
+    }
+
+    Json(json!({
+        "code": 200,
+        "message": null,
+        "content": {
+            "hasProfile": true,
+            "userIdHash": CHANNEL_ID,
+            "nickname": CHANNEL_NICKNAME,
+            "profileImageUrl": null,
+            "loggedIn": true
+        }
+    }))
+    .into_response()
+}
+
+#[derive(Debug, Deserialize)]
+struct PageParams {
+    page: Option<usize>,
+    size: Option<usize>,
+}
+
+// GET /manage/v1/channels/{id}/followers
+async fn get_followers(
+    State(state): State<SharedState>,
+    Path(channel_id): Path<String>,
+    Query(params): Query<PageParams>,
+) -> Response {
+    if let Some(response) = apply_behaviour(&state).await {
+        return response;
+    }
+
+    if channel_id != CHANNEL_ID {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(json!({ "code": 403, "message": "권한이 없습니다.", "content": null })),
+        )
+            .into_response();
+    }
+
+    let page = params.page.unwrap_or(0);
+    let size = params.size.unwrap_or(10).max(1);
+
+    let state = state.lock().unwrap();
+    let total = state.followers.len();
+    let data: Vec<&FollowerItem> = state.followers.iter().skip(page * size).take(size).collect();
+
+    Json(json!({
+        "code": 200,
+        "message": null,
+        "content": {
+            "page": page,
+            "size": size,
+            "totalCount": total,
+            "totalPages": total.div_ceil(size),
+            "data": data
+        }
+    }))
+    .into_response()
+}
+
+// GET /mock/state
+async fn mock_state(State(state): State<SharedState>) -> impl IntoResponse {
+    let state = state.lock().unwrap();
+    Json(json!(*state))
+}
+
+#[derive(Debug, Deserialize)]
+struct AddFollowerRequest {
+    #[serde(rename = "userIdHash")]
+    user_id_hash: Option<String>,
+    nickname: Option<String>,
+}
+
+// POST /mock/followers
+async fn add_follower(
+    State(state): State<SharedState>,
+    Json(payload): Json<AddFollowerRequest>,
+) -> impl IntoResponse {
+    let follower = state.lock().unwrap().follow(payload.user_id_hash, payload.nickname);
+    println!("[MockChzzk] 팔로우: {}", follower.user.nickname);
+    Json(follower)
+}
+
+#[derive(Debug, Deserialize)]
+struct BurstRequest {
+    count: usize,
+}
+
+// POST /mock/followers/burst
+async fn add_burst(
+    State(state): State<SharedState>,
+    Json(payload): Json<BurstRequest>,
+) -> impl IntoResponse {
+    let mut state = state.lock().unwrap();
+    let added: Vec<FollowerItem> = (0..payload.count).map(|_| state.follow(None, None)).collect();
+    println!("[MockChzzk] 팔로우 폭주: {}명", added.len());
+    Json(added)
+}
+
+// DELETE /mock/followers/{userIdHash}
+async fn remove_follower(
+    State(state): State<SharedState>,
+    Path(user_id_hash): Path<String>,
+) -> impl IntoResponse {
+    let mut state = state.lock().unwrap();
+    let before = state.followers.len();
+    state.followers.retain(|f| f.user.user_id_hash != user_id_hash);
+
+    if state.followers.len() == before {
+        return (StatusCode::NOT_FOUND, Json(json!({ "removed": false }))).into_response();
+    }
+    println!("[MockChzzk] 언팔로우: {}", user_id_hash);
+    Json(json!({ "removed": true })).into_response()
+}
+
+#[derive(Debug, Deserialize)]
+struct FaultRequest {
+    mode: FaultMode,
+    count: Option<u32>,
+}
+
+// POST /mock/fault
+async fn set_fault(
+    State(state): State<SharedState>,
+    Json(payload): Json<FaultRequest>,
+) -> impl IntoResponse {
+    let mut state = state.lock().unwrap();
+    state.fault = payload.mode;
+    state.fault_remaining = payload.count.filter(|c| *c > 0);
+    println!("[MockChzzk] 장애 모드: {:?} ({:?}회)", state.fault, state.fault_remaining);
+    Json(json!({ "mode": state.fault, "count": state.fault_remaining }))
+}
+
+#[derive(Debug, Deserialize)]
+struct LatencyRequest {
+    ms: u64,
+}
+
+// POST /mock/latency
+async fn set_latency(
+    State(state): State<SharedState>,
+    Json(payload): Json<LatencyRequest>,
+) -> impl IntoResponse {
+    state.lock().unwrap().latency_ms = payload.ms;
+    println!("[MockChzzk] 응답 지연: {}ms", payload.ms);
+    Json(json!({ "ms": payload.ms }))
+}
+
+// POST /mock/reset
+async fn reset(State(state): State<SharedState>) -> impl IntoResponse {
+    *state.lock().unwrap() = MockState::new();
+    println!("[MockChzzk] 상태 초기화");
+    Json(json!({ "success": true }))
+}
+
+// --port 인자 > FAZZK_MOCK_PORT 환경 변수 > 기본값
+fn parse_port() -> u16 {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--port" {
+            if let Some(port) = args.next().and_then(|p| p.parse().ok()) {
+                return port;
+            }
+        }
+    }
+
+    std::env::var("FAZZK_MOCK_PORT")
+        .ok()
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(DEFAULT_PORT)
+}
+
+#[tokio::main]
+async fn main() {
+    env_logger::Builder::from_default_env()
+        .filter_level(log::LevelFilter::Info)
+        .init();
+
+    let state: SharedState = Arc::new(Mutex::new(MockState::new()));
+
+    let app = Router::new()
+        // 치지직 API 흉내
+        .route("/nng_main/v1/user/getUserStatus", get(get_user_status))
+        .route("/manage/v1/channels/:id/followers", get(get_followers))
+        // 조작 API
+        .route("/mock/state", get(mock_state))
+        .route("/mock/followers", post(add_follower))
+        .route("/mock/followers/burst", post(add_burst))
+        .route("/mock/followers/:user_id_hash", delete(remove_follower))
+        .route("/mock/fault", post(set_fault))
+        .route("/mock/latency", post(set_latency))
+        .route("/mock/reset", post(reset))
+        .with_state(state);
+
+    let port = parse_port();
+    let addr = format!("127.0.0.1:{}", port);
+    let listener = TcpListener::bind(&addr).await.unwrap();
+
+    println!("[MockChzzk] Listening on http://{}", addr);
+    println!("[MockChzzk] 앱 연결: {}=http://{} {}=http://{}", API_BASE_URL_ENV, addr, GAME_API_BASE_URL_ENV, addr);
+
+    axum::serve(listener, app).await.unwrap();
+}
@@ -1,7 +1,99 @@
 use reqwest::header::{HeaderMap, HeaderValue, COOKIE, USER_AGENT};
 use serde::{Deserialize, Serialize};
+use futures_util::future::BoxFuture;
+use std::time::Duration;
 use crate::state::CookieData;
 
+/// 치지직 API 기본 주소
+pub const DEFAULT_API_BASE_URL: &str = "https://api.chzzk.naver.com";
+
+/// 네이버 게임 커뮤니티 API 기본 주소 (로그인 사용자 조회)
+pub const DEFAULT_GAME_API_BASE_URL: &str = "https://comm-api.game.naver.com";
+
+/// 치지직 API 주소를 덮어쓰는 환경 변수 (로컬 목 서버 등)
+pub const API_BASE_URL_ENV: &str = "FAZZK_CHZZK_API_URL";
+
+/// 게임 커뮤니티 API 주소를 덮어쓰는 환경 변수
+pub const GAME_API_BASE_URL_ENV: &str = "FAZZK_GAME_API_URL";
+
+/// 치지직 API 호출 오류
+///
+/// 호출하는 쪽이 세션 만료, 일시적인 네트워크 문제, 응답 형식 변경을 구분해 대응할 수 있도록 나눕니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChzzkError {
+    /// 연결 실패, 타임아웃 등 네트워크 오류
+    Network(String),
+    /// 쿠키(NID_AUT/NID_SES)가 만료되었거나 올바르지 않음
+    Unauthorized,
+    /// 요청 한도 초과 (`Retry-After` 헤더가 있으면 대기 시간 포함)
+    RateLimited { retry_after: Option<Duration> },
+    /// 성공이 아닌 HTTP 상태 코드
+    ServerError(u16),
+    /// 응답 JSON 해석 실패 (API 형식 변경 가능성)
+    Decode(String),
+    /// HTTP는 성공했지만 응답 본문의 code가 실패
+    ApiCode { code: i32, message: Option<String> },
+}
+
+impl std::fmt::Display for ChzzkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Network(e) => write!(f, "Network Error: {}", e),
+            Self::Unauthorized => write!(f, "Unauthorized: session expired or invalid cookies"),
+            Self::RateLimited { retry_after: Some(d) } => write!(f, "Rate Limited: retry after {}s", d.as_secs()),
+            Self::RateLimited { retry_after: None } => write!(f, "Rate Limited"),
+            Self::ServerError(status) => write!(f, "API Error: HTTP {}", status),
+            Self::Decode(e) => write!(f, "Decode Error: {}", e),
+            Self::ApiCode { code, message } => write!(f, "API Error: code {} ({:?})", code, message),
+        }
+    }
+}
+
+impl std::error::Error for ChzzkError {}
+
+impl From<reqwest::Error> for ChzzkError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_decode() {
+            Self::Decode(e.to_string())
+        } else {
+            Self::Network(e.to_string())
+        }
+    }
+}
+
+impl ChzzkError {
+    /// 다시 로그인해야 해결되는 오류인지 여부
+    pub fn is_auth(&self) -> bool {
+        matches!(self, Self::Unauthorized)
+    }
+
+    // 성공이 아닌 HTTP 응답을 오류로 변환
+    fn from_response(res: &reqwest::Response) -> Self {
+        let status = res.status();
+        match status.as_u16() {
+            401 | 403 => Self::Unauthorized,
+            429 => Self::RateLimited {
+                retry_after: res
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.trim().parse::<u64>().ok())
+                    .map(Duration::from_secs),
+            },
+            code => Self::ServerError(code),
+        }
+    }
+
+    // 응답 본문의 code를 오류로 변환
+    fn from_api_code(code: i32, message: Option<String>) -> Self {
+        match code {
+            401 | 403 => Self::Unauthorized,
+            429 => Self::RateLimited { retry_after: None },
+            _ => Self::ApiCode { code, message },
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct UserStatusResponse {
     code: i32,
@@ -9,42 +101,12 @@ struct UserStatusResponse {
     content: Option<UserStatusContent>,
 }
 
+// 로그인되지 않은 쿠키면 userIdHash가 null로 옵니다.
 #[derive(Debug, Deserialize)]
 struct UserStatusContent {
     #[serde(rename = "userIdHash")]
-    user_id_hash: String,
-    nickname: String,
-}
-
-pub async fn get_profile_id(client: &reqwest::Client, cookies: &CookieData) -> Result<(String, String), String> {
-    let url = "https://comm-api.game.naver.com/nng_main/v1/user/getUserStatus";
-    
-    let cookie_str = format!("NID_AUT={}; NID_SES={}", cookies.nid_aut, cookies.nid_ses);
-    
-    let mut headers = HeaderMap::new();
-    headers.insert(COOKIE, HeaderValue::from_str(&cookie_str).map_err(|e| e.to_string())?);
-    headers.insert(USER_AGENT, HeaderValue::from_static("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36"));
-
-    // let client = reqwest::Client::new(); // Use passed client
-    let res = client.get(url)
-        .headers(headers)
-        .send()
-        .await
-        .map_err(|e| e.to_string())?;
-        
-    if !res.status().is_success() {
-        return Err(format!("API Error: {}", res.status()));
-    }
-    
-    let body: UserStatusResponse = res.json().await.map_err(|e| e.to_string())?;
-    
-    if body.code == 200 {
-        if let Some(content) = body.content {
-            return Ok((content.user_id_hash, content.nickname));
-        }
-    }
-    
-    Err(format!("Auth Failed: {:?}", body.message))
+    user_id_hash: Option<String>,
+    nickname: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
@@ -61,7 +123,7 @@ pub struct FollowerContent {
     pub data: Vec<FollowerItem>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
 #[serde(default)]
 pub struct FollowerItem {
     pub user: User,
@@ -69,7 +131,28 @@ pub struct FollowerItem {
     pub following_since: String,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+impl FollowerItem {
+    /// 팔로우 시작 시각 (형식을 알 수 없으면 None)
+    pub fn followed_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        parse_following_since(&self.following_since)
+    }
+}
+
+/// `followingSince` 파싱 - 치지직 형식("YYYY-MM-DD HH:MM:SS", KST)과 RFC 3339 모두 지원
+pub fn parse_following_since(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&chrono::Utc));
+    }
+
+    let kst = chrono::FixedOffset::east_opt(9 * 3600)?;
+    chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+        .ok()?
+        .and_local_timezone(kst)
+        .single()
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
 #[serde(default)]
 pub struct User {
     #[serde(rename = "userIdHash")]
@@ -79,32 +162,302 @@ pub struct User {
     pub profile_image_url: Option<String>,
 }
 
-pub async fn get_followers(client: &reqwest::Client, cookies: &CookieData, user_id_hash: &str) -> Result<FollowerResponse, String> {
-    let url = format!("https://api.chzzk.naver.com/manage/v1/channels/{}/followers?page=0&size=10&userNickname=", user_id_hash);
-    
-    let cookie_str = format!("NID_AUT={}; NID_SES={}", cookies.nid_aut, cookies.nid_ses);
-    
-    let mut headers = HeaderMap::new();
-    headers.insert(COOKIE, HeaderValue::from_str(&cookie_str).map_err(|e| e.to_string())?);
-    headers.insert(USER_AGENT, HeaderValue::from_static("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36"));
-
-    // let client = reqwest::Client::new();
-    let res = client.get(url)
-        .headers(headers)
-        .send()
-        .await
-        .map_err(|e| e.to_string())?;
-        
-    if !res.status().is_success() {
-        return Err(format!("API Error: {}", res.status()));
-    }
-    
-    let body_text = res.text().await.map_err(|e| e.to_string())?;
-    println!("[Chzzk API] Raw Response: {}", &body_text.chars().take(2000).collect::<String>());
-    
-    let body: FollowerResponse = serde_json::from_str(&body_text).map_err(|e| {
-        eprintln!("[Chzzk API] Parse Error: {}", e);
-        e.to_string()
-    })?;
-    Ok(body)
+/// 치지직 팔로워 API 기본 페이지 크기
+pub const DEFAULT_PAGE_SIZE: usize = 10;
+
+/// 한 번에 요청할 수 있는 최대 페이지 크기
+pub const MAX_PAGE_SIZE: usize = 50;
+
+/// 따라잡기 조회 시 기본 최대 페이지 수 (API 과다 호출 방지)
+pub const DEFAULT_MAX_PAGES: usize = 5;
+
+/// 여러 페이지 팔로워 조회 옵션
+#[derive(Debug, Clone)]
+pub struct FollowerPageQuery {
+    /// 페이지당 팔로워 수
+    pub size: usize,
+    /// 최대 조회 페이지 수
+    pub max_pages: usize,
+    /// 마지막으로 확인한 팔로워 - 이 팔로워(또는 더 오래된 팔로워)를 만나면 조회 중단
+    pub until: Option<FollowerItem>,
+}
+
+impl Default for FollowerPageQuery {
+    fn default() -> Self {
+        Self {
+            size: DEFAULT_PAGE_SIZE,
+            max_pages: DEFAULT_MAX_PAGES,
+            until: None,
+        }
+    }
+}
+
+impl FollowerPageQuery {
+    // 마지막으로 확인한 팔로워에 도달했는지 확인 (시각을 해석할 수 없으면 도달하지 않은 것으로 봄)
+    fn reached_known(&self, follower: &FollowerItem) -> bool {
+        let Some(known) = &self.until else {
+            return false;
+        };
+        if follower.user.user_id_hash == known.user.user_id_hash {
+            return true;
+        }
+        match (follower.followed_at(), known.followed_at()) {
+            (Some(followed_at), Some(known_at)) => followed_at <= known_at,
+            _ => false,
+        }
+    }
+}
+
+/// 치지직 API 접속 설정
+#[derive(Debug, Clone)]
+pub struct ChzzkConfig {
+    pub api_base_url: String,
+    pub game_api_base_url: String,
+}
+
+impl Default for ChzzkConfig {
+    fn default() -> Self {
+        Self {
+            api_base_url: DEFAULT_API_BASE_URL.to_string(),
+            game_api_base_url: DEFAULT_GAME_API_BASE_URL.to_string(),
+        }
+    }
+}
+
+impl ChzzkConfig {
+    /// 환경 변수가 있으면 기본 주소 대신 사용합니다.
+    pub fn from_env() -> Self {
+        let read = |key: &str, default: &str| {
+            std::env::var(key)
+                .ok()
+                .map(|v| v.trim().trim_end_matches('/').to_string())
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| default.to_string())
+        };
+
+        Self {
+            api_base_url: read(API_BASE_URL_ENV, DEFAULT_API_BASE_URL),
+            game_api_base_url: read(GAME_API_BASE_URL_ENV, DEFAULT_GAME_API_BASE_URL),
+        }
+    }
+}
+
+/// 치지직 API 클라이언트 인터페이스
+///
+/// 실제 서비스 대신 목 서버나 테스트 구현으로 바꿔 끼울 수 있도록 분리합니다.
+pub trait ChzzkApi: Send + Sync {
+    /// 쿠키로 로그인 사용자의 (userIdHash, 닉네임)을 조회합니다.
+    fn get_profile_id<'a>(&'a self, cookies: &'a CookieData) -> BoxFuture<'a, Result<(String, String), ChzzkError>>;
+
+    /// 팔로워 목록 한 페이지를 조회합니다.
+    fn get_followers_page<'a>(
+        &'a self,
+        cookies: &'a CookieData,
+        user_id_hash: &'a str,
+        page: usize,
+        size: usize,
+    ) -> BoxFuture<'a, Result<FollowerResponse, ChzzkError>>;
+
+    /// 최신 팔로워 첫 페이지를 조회합니다.
+    fn get_followers<'a>(
+        &'a self,
+        cookies: &'a CookieData,
+        user_id_hash: &'a str,
+    ) -> BoxFuture<'a, Result<FollowerResponse, ChzzkError>> {
+        self.get_followers_page(cookies, user_id_hash, 0, DEFAULT_PAGE_SIZE)
+    }
+
+    /// 최신 팔로워부터 페이지를 넘기며 조회합니다.
+    ///
+    /// `query.until`에 지정한 팔로워를 만나거나, 마지막 페이지에 도달하거나,
+    /// `query.max_pages`만큼 조회하면 멈춥니다. 첫 페이지는 항상 전체를 반환합니다.
+    fn get_followers_until<'a>(
+        &'a self,
+        cookies: &'a CookieData,
+        user_id_hash: &'a str,
+        query: &'a FollowerPageQuery,
+    ) -> BoxFuture<'a, Result<Vec<FollowerItem>, ChzzkError>> {
+        Box::pin(async move {
+            let size = query.size.clamp(1, MAX_PAGE_SIZE);
+            let mut followers = Vec::new();
+
+            for page in 0..query.max_pages.max(1) {
+                let response = self.get_followers_page(cookies, user_id_hash, page, size).await?;
+                let data = match response.content {
+                    Some(content) => content.data,
+                    None => return Err(ChzzkError::Decode(format!("no content ({:?})", response.message))),
+                };
+
+                let is_last_page = data.len() < size;
+                let reached_known = data.iter().any(|f| query.reached_known(f));
+                followers.extend(data);
+
+                if is_last_page || reached_known {
+                    return Ok(followers);
+                }
+            }
+
+            log::warn!(
+                "[Chzzk API] Follower page limit reached ({} pages), older follows may be missed",
+                query.max_pages
+            );
+            Ok(followers)
+        })
+    }
+}
+
+/// reqwest 기반 치지직 API 클라이언트
+#[derive(Debug, Clone)]
+pub struct ChzzkClient {
+    client: reqwest::Client, // HTTP 클라이언트 재사용
+    config: ChzzkConfig,
+}
+
+impl ChzzkClient {
+    pub fn new(client: reqwest::Client, config: ChzzkConfig) -> Self {
+        Self { client, config }
+    }
+
+    /// 환경 변수 설정을 반영한 클라이언트를 만듭니다.
+    pub fn from_env() -> Self {
+        let config = ChzzkConfig::from_env();
+        if config.api_base_url != DEFAULT_API_BASE_URL || config.game_api_base_url != DEFAULT_GAME_API_BASE_URL {
+            log::warn!(
+                "[Chzzk API] Using overridden base URLs: {} / {}",
+                config.api_base_url,
+                config.game_api_base_url
+            );
+        }
+        Self::new(reqwest::Client::new(), config)
+    }
+
+    pub fn config(&self) -> &ChzzkConfig {
+        &self.config
+    }
+
+    // 헤더에 넣을 수 없는 쿠키 값은 인증 실패로 처리
+    fn headers(cookies: &CookieData) -> Result<HeaderMap, ChzzkError> {
+        let cookie_str = format!("NID_AUT={}; NID_SES={}", cookies.nid_aut, cookies.nid_ses);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(COOKIE, HeaderValue::from_str(&cookie_str).map_err(|_| ChzzkError::Unauthorized)?);
+        headers.insert(USER_AGENT, HeaderValue::from_static("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36"));
+        Ok(headers)
+    }
+}
+
+impl ChzzkApi for ChzzkClient {
+    fn get_profile_id<'a>(&'a self, cookies: &'a CookieData) -> BoxFuture<'a, Result<(String, String), ChzzkError>> {
+        Box::pin(async move {
+            let url = format!("{}/nng_main/v1/user/getUserStatus", self.config.game_api_base_url);
+
+            let res = self.client.get(url)
+                .headers(Self::headers(cookies)?)
+                .send()
+                .await?;
+
+            if !res.status().is_success() {
+                return Err(ChzzkError::from_response(&res));
+            }
+
+            let body: UserStatusResponse = res.json().await.map_err(|e| ChzzkError::Decode(e.to_string()))?;
+
+            if body.code != 200 {
+                return Err(ChzzkError::from_api_code(body.code, body.message));
+            }
+
+            match body.content {
+                Some(UserStatusContent { user_id_hash: Some(hash), nickname }) => {
+                    Ok((hash, nickname.unwrap_or_default()))
+                }
+                // 응답은 정상이지만 로그인 정보가 없음 - 쿠키 만료
+                _ => Err(ChzzkError::Unauthorized),
+            }
+        })
+    }
+
+    fn get_followers_page<'a>(
+        &'a self,
+        cookies: &'a CookieData,
+        user_id_hash: &'a str,
+        page: usize,
+        size: usize,
+    ) -> BoxFuture<'a, Result<FollowerResponse, ChzzkError>> {
+        Box::pin(async move {
+            let size = size.clamp(1, MAX_PAGE_SIZE);
+            let url = format!(
+                "{}/manage/v1/channels/{}/followers?page={}&size={}&userNickname=",
+                self.config.api_base_url, user_id_hash, page, size
+            );
+
+            let res = self.client.get(url)
+                .headers(Self::headers(cookies)?)
+                .send()
+                .await?;
+
+            if !res.status().is_success() {
+                return Err(ChzzkError::from_response(&res));
+            }
+
+            let body_text = res.text().await?;
+            log::debug!("[Chzzk API] Raw Response: {}", &body_text.chars().take(2000).collect::<String>());
+
+            let body: FollowerResponse = serde_json::from_str(&body_text).map_err(|e| {
+                log::warn!("[Chzzk API] Parse Error: {}", e);
+                ChzzkError::Decode(e.to_string())
+            })?;
+
+            if body.code != 200 {
+                return Err(ChzzkError::from_api_code(body.code, body.message));
+            }
+            Ok(body)
+        })
+    }
+}
+
+/// 테스트에서 함께 쓰는 팔로워 데이터
+#[cfg(test)]
+pub mod test_support {
+    use super::{FollowerItem, User};
+
+    /// 팔로우 시각이 2024-01-01 09:00:00인 팔로워
+    pub fn follower(hash: &str, nickname: &str) -> FollowerItem {
+        FollowerItem {
+            user: User {
+                user_id_hash: hash.to_string(),
+                nickname: nickname.to_string(),
+                profile_image_url: None,
+            },
+            following_since: "2024-01-01 09:00:00".to_string(),
+        }
+    }
+
+    /// 팔로우 시각을 지정한 팔로워 (닉네임은 해시와 같음)
+    pub fn follower_since(hash: &str, following_since: &str) -> FollowerItem {
+        FollowerItem {
+            following_since: following_since.to_string(),
+            ..follower(hash, hash)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_support::follower_since;
+    use super::*;
+
+    #[test]
+    fn reached_known_compares_parsed_timestamps() {
+        let query = FollowerPageQuery {
+            until: Some(follower_since("known", "2024-01-01 09:00:00")),
+            ..FollowerPageQuery::default()
+        };
+
+        assert!(query.reached_known(&follower_since("known", "garbage")));
+        // 같은 시각의 RFC 3339 표기 (KST 09:00 = UTC 00:00)
+        assert!(query.reached_known(&follower_since("older", "2024-01-01T00:00:00Z")));
+        assert!(!query.reached_known(&follower_since("newer", "2024-01-01 09:00:01")));
+        // 문자열로는 더 앞서지만 해석할 수 없는 시각은 도달하지 않은 것으로 봄
+        assert!(!query.reached_known(&follower_since("unknown", "2023/12/31 09:00")));
+    }
 }
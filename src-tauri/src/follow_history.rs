@@ -0,0 +1,269 @@
+//! 사용자별 팔로우 기록과 재팔로우 쿨다운
+//!
+//! 팔로우/언팔로우를 반복하는 사용자가 매번 알림을 띄우지 못하도록
+//! 사용자별로 처음 팔로우인지 재팔로우인지 판단하고, 쿨다운 안의 재팔로우는
+//! 알림 없이 조용히 전달하거나 아예 무시합니다.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// 기록을 유지하는 최대 사용자 수 (초과 시 가장 오래 전에 팔로우한 사용자부터 삭제)
+pub const MAX_HISTORY_ENTRIES: usize = 1000;
+
+/// 기본 재팔로우 쿨다운
+pub const DEFAULT_REFOLLOW_COOLDOWN: Duration = Duration::from_secs(600);
+
+/// 쿨다운 안의 재팔로우 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CooldownMode {
+    /// 알림 없는 이벤트로 전달 (`silent: true`)
+    #[default]
+    Silent,
+    /// 전달하지 않음
+    Suppress,
+}
+
+impl CooldownMode {
+    pub fn from_setting(value: &serde_json::Value) -> Self {
+        match value.as_str() {
+            Some("suppress") => Self::Suppress,
+            _ => Self::Silent,
+        }
+    }
+}
+
+/// 재팔로우 쿨다운 설정
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CooldownPolicy {
+    pub window: Duration,
+    pub mode: CooldownMode,
+}
+
+impl Default for CooldownPolicy {
+    fn default() -> Self {
+        Self {
+            window: DEFAULT_REFOLLOW_COOLDOWN,
+            mode: CooldownMode::default(),
+        }
+    }
+}
+
+/// 사용자 한 명의 팔로우 기록
+#[derive(Debug, Clone, Serialize)]
+pub struct FollowRecord {
+    #[serde(rename = "firstFollowedAt")]
+    pub first_followed_at: u128,
+    #[serde(rename = "lastFollowedAt")]
+    pub last_followed_at: u128,
+    #[serde(rename = "lastAlertedAt")]
+    pub last_alerted_at: Option<u128>,
+    #[serde(rename = "followCount")]
+    pub follow_count: u32,
+    #[serde(rename = "unfollowCount")]
+    pub unfollow_count: u32,
+}
+
+/// 팔로우 처리 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FollowDecision {
+    /// 알림 표시
+    Alert { is_refollow: bool },
+    /// 쿨다운 중 - 알림 없는 이벤트로 전달
+    Silent,
+    /// 쿨다운 중 - 전달하지 않음
+    Suppressed,
+}
+
+/// 사용자별 팔로우 기록 (userIdHash 기준)
+#[derive(Debug, Default)]
+pub struct FollowHistory {
+    records: HashMap<String, FollowRecord>,
+}
+
+impl FollowHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, user_id_hash: &str) -> Option<&FollowRecord> {
+        self.records.get(user_id_hash)
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// 알림 없이 팔로우 중인 사용자로 등록 (기준점 초기화 시)
+    pub fn seed(&mut self, user_id_hash: &str, now_ms: u128) {
+        self.records.entry(user_id_hash.to_string()).or_insert_with(|| FollowRecord {
+            first_followed_at: now_ms,
+            last_followed_at: now_ms,
+            last_alerted_at: None,
+            follow_count: 1,
+            unfollow_count: 0,
+        });
+        self.evict();
+    }
+
+    /// 팔로우를 기록하고 알림 여부를 결정합니다.
+    ///
+    /// `known_refollow`는 변화 감지 엔진이 재팔로우로 판단한 경우입니다.
+    /// 기록이 있는 사용자도 재팔로우로 봅니다. `bypass_cooldown`이면 쿨다운을 적용하지 않습니다.
+    pub fn record_follow(
+        &mut self,
+        user_id_hash: &str,
+        known_refollow: bool,
+        bypass_cooldown: bool,
+        policy: CooldownPolicy,
+        now_ms: u128,
+    ) -> FollowDecision {
+        let record = self.records.entry(user_id_hash.to_string()).or_insert_with(|| FollowRecord {
+            first_followed_at: now_ms,
+            last_followed_at: now_ms,
+            last_alerted_at: None,
+            follow_count: 0,
+            unfollow_count: 0,
+        });
+
+        let is_refollow = known_refollow || record.follow_count > 0;
+        record.follow_count += 1;
+        record.last_followed_at = now_ms;
+
+        let in_cooldown = is_refollow
+            && !bypass_cooldown
+            && record
+                .last_alerted_at
+                .is_some_and(|alerted| now_ms.saturating_sub(alerted) < policy.window.as_millis());
+
+        let decision = if !in_cooldown {
+            record.last_alerted_at = Some(now_ms);
+            FollowDecision::Alert { is_refollow }
+        } else {
+            match policy.mode {
+                CooldownMode::Silent => FollowDecision::Silent,
+                CooldownMode::Suppress => FollowDecision::Suppressed,
+            }
+        };
+
+        self.evict();
+        decision
+    }
+
+    /// 언팔로우 기록
+    pub fn record_unfollow(&mut self, user_id_hash: &str) {
+        if let Some(record) = self.records.get_mut(user_id_hash) {
+            record.unfollow_count += 1;
+        }
+    }
+
+    // 최대 개수를 넘으면 가장 오래 전에 팔로우한 사용자 기록 삭제
+    fn evict(&mut self) {
+        while self.records.len() > MAX_HISTORY_ENTRIES {
+            let oldest = self
+                .records
+                .iter()
+                .min_by_key(|(_, record)| record.last_followed_at)
+                .map(|(hash, _)| hash.clone());
+            match oldest {
+                Some(hash) => {
+                    self.records.remove(&hash);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: u128 = 60_000;
+
+    fn policy(mode: CooldownMode) -> CooldownPolicy {
+        CooldownPolicy {
+            window: Duration::from_secs(600),
+            mode,
+        }
+    }
+
+    #[test]
+    fn first_follow_alerts_as_first_time() {
+        let mut history = FollowHistory::new();
+
+        let decision = history.record_follow("a", false, false, policy(CooldownMode::Silent), 0);
+
+        assert_eq!(decision, FollowDecision::Alert { is_refollow: false });
+    }
+
+    #[test]
+    fn refollow_inside_cooldown_is_silent() {
+        let mut history = FollowHistory::new();
+        history.record_follow("a", false, false, policy(CooldownMode::Silent), 0);
+        history.record_unfollow("a");
+
+        let decision = history.record_follow("a", true, false, policy(CooldownMode::Silent), MINUTE);
+
+        assert_eq!(decision, FollowDecision::Silent);
+        assert_eq!(history.get("a").map(|r| r.follow_count), Some(2));
+        assert_eq!(history.get("a").map(|r| r.unfollow_count), Some(1));
+    }
+
+    #[test]
+    fn refollow_inside_cooldown_can_be_suppressed() {
+        let mut history = FollowHistory::new();
+        history.record_follow("a", false, false, policy(CooldownMode::Suppress), 0);
+
+        let decision = history.record_follow("a", false, false, policy(CooldownMode::Suppress), MINUTE);
+
+        assert_eq!(decision, FollowDecision::Suppressed);
+    }
+
+    #[test]
+    fn refollow_after_cooldown_alerts_as_refollow() {
+        let mut history = FollowHistory::new();
+        history.record_follow("a", false, false, policy(CooldownMode::Silent), 0);
+        history.record_follow("a", true, false, policy(CooldownMode::Silent), MINUTE);
+
+        // 쿨다운은 마지막 알림 기준이므로 조용히 넘긴 팔로우는 기준이 되지 않음
+        let decision = history.record_follow("a", true, false, policy(CooldownMode::Silent), 11 * MINUTE);
+
+        assert_eq!(decision, FollowDecision::Alert { is_refollow: true });
+    }
+
+    #[test]
+    fn bypass_ignores_cooldown() {
+        let mut history = FollowHistory::new();
+        history.record_follow("a", false, false, policy(CooldownMode::Suppress), 0);
+
+        let decision = history.record_follow("a", true, true, policy(CooldownMode::Suppress), MINUTE);
+
+        assert_eq!(decision, FollowDecision::Alert { is_refollow: true });
+    }
+
+    #[test]
+    fn seeded_follower_counts_as_refollow_without_cooldown() {
+        let mut history = FollowHistory::new();
+        history.seed("a", 0);
+
+        let decision = history.record_follow("a", false, false, policy(CooldownMode::Silent), MINUTE);
+
+        assert_eq!(decision, FollowDecision::Alert { is_refollow: true });
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut history = FollowHistory::new();
+        for i in 0..MAX_HISTORY_ENTRIES + 5 {
+            history.seed(&i.to_string(), i as u128);
+        }
+
+        assert_eq!(history.len(), MAX_HISTORY_ENTRIES);
+        assert!(history.get("0").is_none());
+    }
+}
@@ -0,0 +1,485 @@
+//! 팔로워 변화 감지 엔진
+//!
+//! 이전 스냅샷과 새로 조회한 팔로워 목록을 비교해 이벤트를 만들어냅니다.
+//! 네트워크나 잠금 없이 동작하므로 모니터 루프와 분리해 테스트할 수 있습니다.
+//!
+//! 치지직 팔로워 API는 최신 팔로우 순으로 한 페이지만 돌려주므로 팔로워 수는
+//! 거의 변하지 않습니다. 그래서 수 대신 `userIdHash` + `followingSince` 조합과
+//! 목록 순서를 기준으로 변화를 판단합니다.
+
+use crate::chzzk::{FollowerItem, DEFAULT_PAGE_SIZE};
+use crate::state::CompressedFollower;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+/// 압축 저장하는 팔로워 최대 개수 (메모리 효율성)
+pub const MAX_TRACKED_FOLLOWERS: usize = 100;
+
+/// 재팔로우 판단용으로 기억하는 언팔로우 사용자 최대 수 (오래된 것부터 잊음)
+pub const MAX_DEPARTED_FOLLOWERS: usize = 1000;
+
+/// 스냅샷 비교 결과로 발생하는 이벤트
+#[derive(Debug, Clone, PartialEq)]
+pub enum FollowerEvent {
+    /// 처음 보는 팔로워
+    NewFollow(FollowerItem),
+    /// 언팔로우 후 다시 팔로우한 팔로워
+    Refollow(FollowerItem),
+    /// 이전 스냅샷에서 사라진 팔로워
+    Unfollow(FollowerItem),
+    /// 변화 없음
+    Unchanged,
+}
+
+/// 팔로워 목록 비교 엔진
+#[derive(Debug)]
+pub struct FollowerDiffEngine {
+    snapshot: Option<Vec<FollowerItem>>,
+    tracked: VecDeque<CompressedFollower>,
+    departed: VecDeque<u64>,
+    page_size: usize,
+}
+
+impl Default for FollowerDiffEngine {
+    fn default() -> Self {
+        Self {
+            snapshot: None,
+            tracked: VecDeque::new(),
+            departed: VecDeque::new(),
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+impl FollowerDiffEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// API 페이지 크기 지정 (조회한 목록이 잘렸는지 판단할 때 사용, 조회 시 페이지 크기와 같아야 함)
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// 저장해 둔 팔로워 목록(최신순)을 기준점으로 설정 (재시작 시 복원)
+    pub fn with_baseline(mut self, baseline: Vec<FollowerItem>) -> Self {
+        self.diff(baseline);
+        self
+    }
+
+    /// 첫 스냅샷(기준점)이 설정되었는지 여부
+    pub fn is_initialized(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    /// 마지막으로 비교한 팔로워 목록
+    pub fn snapshot(&self) -> Option<&[FollowerItem]> {
+        self.snapshot.as_deref()
+    }
+
+    /// 압축 저장된 팔로워 목록 (최대 100명)
+    pub fn tracked(&self) -> &VecDeque<CompressedFollower> {
+        &self.tracked
+    }
+
+    /// 새로 조회한 팔로워 목록(최신순)을 이전 스냅샷과 비교합니다.
+    ///
+    /// 첫 호출은 기준점만 저장하고 `Unchanged`를 반환합니다.
+    /// 새 팔로우는 팔로우한 순서(오래된 것 먼저)로, 언팔로우는 그 뒤에 담깁니다.
+    /// 이벤트가 하나도 없으면 `Unchanged` 하나만 담아 반환합니다.
+    pub fn diff(&mut self, current: Vec<FollowerItem>) -> Vec<FollowerEvent> {
+        let Some(previous) = self.snapshot.take() else {
+            for follower in &current {
+                self.track(CompressedFollower::from_follower(follower));
+            }
+            self.snapshot = Some(current);
+            return vec![FollowerEvent::Unchanged];
+        };
+
+        let mut events = self.detect_follows(&previous, &current);
+        events.extend(self.detect_unfollows(&previous, &current));
+
+        self.snapshot = Some(current);
+
+        if events.is_empty() {
+            events.push(FollowerEvent::Unchanged);
+        }
+        events
+    }
+
+    // 이전 스냅샷에 그대로 남아 있는 첫 팔로워(기준점)보다 위에 있는 팔로워가 새 팔로우입니다.
+    // 기준점 아래에 새로 보이는 팔로워는 언팔로우로 목록이 당겨져 들어온 기존 팔로워입니다.
+    fn detect_follows(&mut self, previous: &[FollowerItem], current: &[FollowerItem]) -> Vec<FollowerEvent> {
+        let anchor = current.iter().position(|f| contains_same_follow(previous, f));
+        let newest_previous = previous.first();
+
+        let candidates = match anchor {
+            Some(index) => &current[..index],
+            None => current,
+        };
+
+        let mut events = Vec::new();
+        for follower in candidates.iter().rev() {
+            if contains_same_follow(previous, follower) {
+                continue;
+            }
+
+            // 기준점이 없으면 (전부 언팔로우됐거나 페이지보다 많은 팔로우) 시각으로 판단
+            if anchor.is_none() {
+                if let Some(newest) = newest_previous {
+                    if compare_follow_time(follower, newest).is_lt() {
+                        continue;
+                    }
+                }
+            }
+
+            let compressed = CompressedFollower::from_follower(follower);
+            let was_following = previous
+                .iter()
+                .any(|f| f.user.user_id_hash == follower.user.user_id_hash);
+
+            if self.forget_departed(compressed.hash) || was_following {
+                events.push(FollowerEvent::Refollow(follower.clone()));
+            } else {
+                events.push(FollowerEvent::NewFollow(follower.clone()));
+            }
+            self.track(compressed);
+        }
+        events
+    }
+
+    // 마지막 페이지가 가득 차 있으면 (여러 페이지를 이어 붙인 목록 포함) 그 아래에 팔로워가 더 있을 수 있으므로,
+    // 가장 오래된 팔로워보다 오래된 항목은 새 팔로우에 밀려났을 수 있어 언팔로우로 보지 않습니다.
+    fn detect_unfollows(&mut self, previous: &[FollowerItem], current: &[FollowerItem]) -> Vec<FollowerEvent> {
+        let truncated = !current.is_empty() && current.len().is_multiple_of(self.page_size);
+        let oldest_current = current.last();
+
+        let mut events = Vec::new();
+        for follower in previous {
+            let still_following = current
+                .iter()
+                .any(|f| f.user.user_id_hash == follower.user.user_id_hash);
+            if still_following {
+                continue;
+            }
+
+            if truncated {
+                if let Some(oldest) = oldest_current {
+                    if compare_follow_time(follower, oldest).is_le() {
+                        continue;
+                    }
+                }
+            }
+
+            let compressed = CompressedFollower::from_follower(follower);
+            self.tracked.retain(|cf| cf.hash != compressed.hash);
+            self.remember_departed(compressed.hash);
+            events.push(FollowerEvent::Unfollow(follower.clone()));
+        }
+        events
+    }
+
+    fn track(&mut self, compressed: CompressedFollower) {
+        if self.tracked.iter().any(|cf| cf.hash == compressed.hash) {
+            return;
+        }
+        self.tracked.push_back(compressed);
+        if self.tracked.len() > MAX_TRACKED_FOLLOWERS {
+            self.tracked.pop_front();
+        }
+    }
+
+    // 언팔로우한 사용자 기록 (다시 언팔로우하면 최신으로 옮김, 최대 MAX_DEPARTED_FOLLOWERS명)
+    fn remember_departed(&mut self, hash: u64) {
+        self.forget_departed(hash);
+        self.departed.push_back(hash);
+        if self.departed.len() > MAX_DEPARTED_FOLLOWERS {
+            self.departed.pop_front();
+        }
+    }
+
+    // 언팔로우 기록에서 제거하고, 기록이 있었는지 반환
+    fn forget_departed(&mut self, hash: u64) -> bool {
+        match self.departed.iter().position(|h| *h == hash) {
+            Some(index) => {
+                self.departed.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+// 같은 사용자가 같은 시각에 팔로우한 항목이 있는지 확인
+fn contains_same_follow(list: &[FollowerItem], follower: &FollowerItem) -> bool {
+    list.iter().any(|f| {
+        f.user.user_id_hash == follower.user.user_id_hash && compare_follow_time(f, follower).is_eq()
+    })
+}
+
+// 팔로우 시각 비교 (초 단위로 해석해 형식/정밀도 차이를 무시하고, 해석할 수 없으면 문자열로 비교)
+fn compare_follow_time(a: &FollowerItem, b: &FollowerItem) -> Ordering {
+    match (a.followed_at(), b.followed_at()) {
+        (Some(a_at), Some(b_at)) => a_at.timestamp().cmp(&b_at.timestamp()),
+        _ => a.following_since.cmp(&b.following_since),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chzzk::test_support::follower_since;
+
+    // (id, 일) 목록을 최신순 페이지로 변환
+    fn page(entries: &[(&str, u32)]) -> Vec<FollowerItem> {
+        entries
+            .iter()
+            .map(|(id, day)| follower_since(id, &format!("2025-01-{:02} 00:00:00", day)))
+            .collect()
+    }
+
+    fn engine_with(page_size: usize, baseline: &[(&str, u32)]) -> FollowerDiffEngine {
+        let mut engine = FollowerDiffEngine::new().with_page_size(page_size);
+        engine.diff(page(baseline));
+        engine
+    }
+
+    #[test]
+    fn first_diff_only_sets_baseline() {
+        let mut engine = FollowerDiffEngine::new();
+        assert!(!engine.is_initialized());
+
+        let events = engine.diff(page(&[("b", 2), ("a", 1)]));
+
+        assert_eq!(events, vec![FollowerEvent::Unchanged]);
+        assert!(engine.is_initialized());
+        assert_eq!(engine.tracked().len(), 2);
+    }
+
+    #[test]
+    fn same_list_is_unchanged() {
+        let mut engine = engine_with(10, &[("b", 2), ("a", 1)]);
+
+        assert_eq!(engine.diff(page(&[("b", 2), ("a", 1)])), vec![FollowerEvent::Unchanged]);
+    }
+
+    #[test]
+    fn new_follow_on_growing_list() {
+        let mut engine = engine_with(10, &[("b", 2), ("a", 1)]);
+
+        let events = engine.diff(page(&[("c", 3), ("b", 2), ("a", 1)]));
+
+        assert_eq!(events, vec![FollowerEvent::NewFollow(page(&[("c", 3)]).remove(0))]);
+    }
+
+    #[test]
+    fn new_follow_pushing_oldest_off_full_page() {
+        let mut engine = engine_with(3, &[("c", 3), ("b", 2), ("a", 1)]);
+
+        let events = engine.diff(page(&[("d", 4), ("c", 3), ("b", 2)]));
+
+        assert_eq!(events, vec![FollowerEvent::NewFollow(page(&[("d", 4)]).remove(0))]);
+    }
+
+    #[test]
+    fn burst_larger_than_page_reports_in_follow_order() {
+        let mut engine = engine_with(2, &[("b", 2), ("a", 1)]);
+
+        let events = engine.diff(page(&[("d", 4), ("c", 3)]));
+
+        assert_eq!(
+            events,
+            vec![
+                FollowerEvent::NewFollow(page(&[("c", 3)]).remove(0)),
+                FollowerEvent::NewFollow(page(&[("d", 4)]).remove(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn burst_spanning_two_pages_keeps_unfollows_on_last_page() {
+        let mut engine = engine_with(3, &[("b", 2), ("a", 1)]);
+
+        // 4명이 팔로우하고 a가 언팔로우 - 두 번째 페이지가 마지막 페이지라 목록 전체를 받음
+        let events = engine.diff(page(&[("f", 6), ("e", 5), ("d", 4), ("c", 3), ("b", 2)]));
+
+        assert_eq!(
+            events,
+            vec![
+                FollowerEvent::NewFollow(page(&[("c", 3)]).remove(0)),
+                FollowerEvent::NewFollow(page(&[("d", 4)]).remove(0)),
+                FollowerEvent::NewFollow(page(&[("e", 5)]).remove(0)),
+                FollowerEvent::NewFollow(page(&[("f", 6)]).remove(0)),
+                FollowerEvent::Unfollow(page(&[("a", 1)]).remove(0)),
+            ]
+        );
+
+        // 두 페이지가 모두 가득 차면 그 아래는 알 수 없으므로 밀려난 팔로워를 언팔로우로 보지 않음
+        let events = engine.diff(page(&[("h", 8), ("g", 7), ("f", 6), ("e", 5), ("d", 4), ("c", 3)]));
+
+        assert_eq!(
+            events,
+            vec![
+                FollowerEvent::NewFollow(page(&[("g", 7)]).remove(0)),
+                FollowerEvent::NewFollow(page(&[("h", 8)]).remove(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn unfollow_on_partial_page() {
+        let mut engine = engine_with(10, &[("c", 3), ("b", 2), ("a", 1)]);
+
+        let events = engine.diff(page(&[("c", 3), ("a", 1)]));
+
+        assert_eq!(events, vec![FollowerEvent::Unfollow(page(&[("b", 2)]).remove(0))]);
+        assert_eq!(engine.tracked().len(), 2);
+    }
+
+    #[test]
+    fn unfollow_on_full_page_ignores_shifted_in_follower() {
+        let mut engine = engine_with(3, &[("d", 4), ("c", 3), ("b", 2)]);
+
+        let events = engine.diff(page(&[("d", 4), ("b", 2), ("a", 1)]));
+
+        assert_eq!(events, vec![FollowerEvent::Unfollow(page(&[("c", 3)]).remove(0))]);
+    }
+
+    #[test]
+    fn follow_and_unfollow_in_same_poll() {
+        let mut engine = engine_with(10, &[("b", 2), ("a", 1)]);
+
+        let events = engine.diff(page(&[("c", 3), ("a", 1)]));
+
+        assert_eq!(
+            events,
+            vec![
+                FollowerEvent::NewFollow(page(&[("c", 3)]).remove(0)),
+                FollowerEvent::Unfollow(page(&[("b", 2)]).remove(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn everyone_unfollowed_and_older_followers_shift_in() {
+        let mut engine = engine_with(2, &[("b", 5), ("a", 4)]);
+
+        let events = engine.diff(page(&[("y", 2), ("x", 1)]));
+
+        assert_eq!(
+            events,
+            vec![
+                FollowerEvent::Unfollow(page(&[("b", 5)]).remove(0)),
+                FollowerEvent::Unfollow(page(&[("a", 4)]).remove(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn refollow_between_polls_changes_following_since() {
+        let mut engine = engine_with(10, &[("b", 2), ("a", 1)]);
+
+        let events = engine.diff(page(&[("a", 3), ("b", 2)]));
+
+        assert_eq!(events, vec![FollowerEvent::Refollow(page(&[("a", 3)]).remove(0))]);
+    }
+
+    #[test]
+    fn follow_after_unfollow_is_refollow() {
+        let mut engine = engine_with(10, &[("b", 2), ("a", 1)]);
+        engine.diff(page(&[("a", 1)]));
+
+        let events = engine.diff(page(&[("b", 3), ("a", 1)]));
+
+        assert_eq!(events, vec![FollowerEvent::Refollow(page(&[("b", 3)]).remove(0))]);
+    }
+
+    #[test]
+    fn empty_page_after_baseline_unfollows_everyone() {
+        let mut engine = engine_with(10, &[("a", 1)]);
+
+        let events = engine.diff(Vec::new());
+
+        assert_eq!(events, vec![FollowerEvent::Unfollow(page(&[("a", 1)]).remove(0))]);
+        assert!(engine.tracked().is_empty());
+    }
+
+    #[test]
+    fn first_follower_of_empty_channel() {
+        let mut engine = engine_with(10, &[]);
+
+        let events = engine.diff(page(&[("a", 1)]));
+
+        assert_eq!(events, vec![FollowerEvent::NewFollow(page(&[("a", 1)]).remove(0))]);
+    }
+
+    #[test]
+    fn tracked_list_is_bounded() {
+        let ids: Vec<String> = (0..MAX_TRACKED_FOLLOWERS + 20).map(|i| i.to_string()).collect();
+        let entries: Vec<(&str, u32)> = ids.iter().map(|id| (id.as_str(), 1)).collect();
+
+        let engine = engine_with(10, &entries);
+
+        assert_eq!(engine.tracked().len(), MAX_TRACKED_FOLLOWERS);
+    }
+
+    #[test]
+    fn mixed_timestamp_formats_compare_parsed_times() {
+        // 치지직 형식(KST)으로 받은 기준점을 RFC 3339(UTC, 밀리초) 응답과 비교
+        let mut engine = FollowerDiffEngine::new().with_page_size(2);
+        engine.diff(vec![follower_since("b", "2025-01-02 08:00:00"), follower_since("a", "2025-01-02 07:00:00")]);
+
+        let c = follower_since("c", "2025-01-02T00:00:00.000Z");
+        let events = engine.diff(vec![c.clone(), follower_since("b", "2025-01-01T23:00:00.000Z")]);
+
+        // b는 같은 팔로우, a는 문자열로는 더 늦지만 실제로는 b보다 먼저 팔로우해 페이지에서 밀려남
+        assert_eq!(events, vec![FollowerEvent::NewFollow(c)]);
+
+        // 기준점이 없을 때도 시각으로 비교: x는 b보다 먼저 팔로우한 기존 팔로워
+        let mut engine = FollowerDiffEngine::new().with_page_size(1);
+        let b = follower_since("b", "2025-01-04T23:00:00Z");
+        engine.diff(vec![b.clone()]);
+
+        let events = engine.diff(vec![follower_since("x", "2025-01-05 07:00:00")]);
+
+        assert_eq!(events, vec![FollowerEvent::Unfollow(b)]);
+    }
+
+    #[test]
+    fn departed_list_is_bounded() {
+        let ids: Vec<String> = (0..MAX_DEPARTED_FOLLOWERS + 5).map(|i| i.to_string()).collect();
+        let entries: Vec<(&str, u32)> = ids.iter().map(|id| (id.as_str(), 1)).collect();
+        let mut engine = engine_with(usize::MAX, &entries);
+
+        engine.diff(Vec::new());
+
+        assert_eq!(engine.departed.len(), MAX_DEPARTED_FOLLOWERS);
+        // 가장 먼저 잊힌 사용자는 새 팔로우, 기억하는 사용자는 재팔로우
+        let events = engine.diff(page(&[("5", 3), ("0", 2)]));
+        assert_eq!(
+            events,
+            vec![
+                FollowerEvent::NewFollow(page(&[("0", 2)]).remove(0)),
+                FollowerEvent::Refollow(page(&[("5", 3)]).remove(0)),
+            ]
+        );
+        assert_eq!(engine.departed.len(), MAX_DEPARTED_FOLLOWERS - 1);
+    }
+
+    #[test]
+    fn restored_baseline_reports_follows_made_while_offline() {
+        let mut engine = FollowerDiffEngine::new().with_page_size(10).with_baseline(page(&[("a", 2), ("b", 1)]));
+
+        let events = engine.diff(page(&[("d", 4), ("c", 3), ("a", 2), ("b", 1)]));
+
+        assert!(engine.is_initialized());
+        assert_eq!(
+            events,
+            vec![
+                FollowerEvent::NewFollow(page(&[("c", 3)]).remove(0)),
+                FollowerEvent::NewFollow(page(&[("d", 4)]).remove(0)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! 팔로우 목표
+//!
+//! 목표 팔로워 수, 시작 기준점, 표시 문구를 설정 스토어(`followGoal`)에 저장하고,
+//! 모니터가 팔로우/언팔로우를 감지할 때마다 현재 값을 갱신해 `goal_progress`로 전달합니다.
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+/// 설정 스토어 키
+pub const GOAL_STORE_KEY: &str = "followGoal";
+
+/// 기본 표시 문구
+pub const DEFAULT_GOAL_LABEL: &str = "팔로우 목표";
+
+/// 저장되는 팔로우 목표
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FollowGoal {
+    pub label: String,
+    /// 목표 팔로워 수
+    pub target: u64,
+    /// 목표를 시작(또는 초기화)할 때의 팔로워 수
+    #[serde(rename = "startCount")]
+    pub start_count: u64,
+    /// 현재 팔로워 수 (기준점 + 감지된 팔로우 - 언팔로우)
+    #[serde(rename = "currentCount")]
+    pub current_count: u64,
+    #[serde(rename = "startedAt")]
+    pub started_at: String,
+}
+
+impl FollowGoal {
+    pub fn new(label: Option<String>, target: u64, start_count: u64) -> Self {
+        Self {
+            label: label
+                .filter(|l| !l.trim().is_empty())
+                .unwrap_or_else(|| DEFAULT_GOAL_LABEL.to_string()),
+            target,
+            start_count,
+            current_count: start_count,
+            started_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// 감지된 팔로우/언팔로우 반영, 값이 바뀌면 true
+    pub fn apply(&mut self, gained: u64, lost: u64) -> bool {
+        let before = self.current_count;
+        self.current_count = self.current_count.saturating_add(gained).saturating_sub(lost);
+        self.current_count != before
+    }
+
+    /// 기준점을 다시 잡음 (값이 없으면 현재 팔로워 수 기준)
+    pub fn reset(&mut self, start_count: Option<u64>) {
+        let start_count = start_count.unwrap_or(self.current_count);
+        self.start_count = start_count;
+        self.current_count = start_count;
+        self.started_at = chrono::Utc::now().to_rfc3339();
+    }
+
+    pub fn progress(&self) -> GoalProgress {
+        let reached = self.current_count >= self.target;
+        let percent = if self.target > self.start_count {
+            let done = self.current_count.saturating_sub(self.start_count) as f64;
+            (done / (self.target - self.start_count) as f64 * 100.0).min(100.0)
+        } else if reached {
+            100.0
+        } else {
+            0.0
+        };
+
+        GoalProgress {
+            goal: self.clone(),
+            gained: self.current_count as i64 - self.start_count as i64,
+            remaining: self.target.saturating_sub(self.current_count),
+            percent,
+            reached,
+        }
+    }
+}
+
+/// 오버레이에 보내는 목표 진행 상황
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoalProgress {
+    #[serde(flatten)]
+    pub goal: FollowGoal,
+    /// 시작 이후 순증가 (언팔로우가 더 많으면 음수)
+    pub gained: i64,
+    pub remaining: u64,
+    /// 진행률 (0~100)
+    pub percent: f64,
+    pub reached: bool,
+}
+
+/// 목표 설정 요청 (`POST /goal`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct GoalRequest {
+    pub label: Option<String>,
+    pub target: u64,
+    /// 시작 팔로워 수 (없으면 기존 목표의 현재 값, 기존 목표가 없으면 0)
+    #[serde(rename = "startCount")]
+    pub start_count: Option<u64>,
+}
+
+/// 목표 초기화 요청 (`POST /goal/reset`)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GoalResetRequest {
+    #[serde(rename = "startCount")]
+    pub start_count: Option<u64>,
+}
+
+/// 설정 스토어에서 목표 읽기
+pub fn load(app_handle: &AppHandle) -> Option<FollowGoal> {
+    use tauri_plugin_store::StoreExt;
+
+    let value = app_handle.store("settings.json").ok()?.get(GOAL_STORE_KEY)?;
+    if value.is_null() {
+        return None;
+    }
+    match serde_json::from_value(value) {
+        Ok(goal) => Some(goal),
+        Err(e) => {
+            log::warn!("[Goal] 저장된 목표를 읽을 수 없습니다: {}", e);
+            None
+        }
+    }
+}
+
+/// 설정 스토어에 목표 저장 (None이면 삭제)
+pub fn save(app_handle: &AppHandle, goal: Option<&FollowGoal>) -> Result<(), String> {
+    use tauri_plugin_store::StoreExt;
+
+    let store = app_handle.store("settings.json").map_err(|e| e.to_string())?;
+    match goal {
+        Some(goal) => store.set(GOAL_STORE_KEY, serde_json::to_value(goal).map_err(|e| e.to_string())?),
+        None => {
+            store.delete(GOAL_STORE_KEY);
+        }
+    }
+    store.save().map_err(|e| format!("목표 저장 실패: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_is_relative_to_start_count() {
+        let mut goal = FollowGoal::new(Some("1000명 가자".to_string()), 1000, 900);
+        goal.apply(30, 5);
+
+        let progress = goal.progress();
+
+        assert_eq!(progress.goal.current_count, 925);
+        assert_eq!(progress.gained, 25);
+        assert_eq!(progress.remaining, 75);
+        assert!((progress.percent - 25.0).abs() < f64::EPSILON);
+        assert!(!progress.reached);
+    }
+
+    #[test]
+    fn reached_goal_is_capped_at_100_percent() {
+        let mut goal = FollowGoal::new(None, 10, 0);
+        goal.apply(12, 0);
+
+        let progress = goal.progress();
+
+        assert_eq!(progress.goal.label, DEFAULT_GOAL_LABEL);
+        assert!(progress.reached);
+        assert_eq!(progress.percent, 100.0);
+        assert_eq!(progress.remaining, 0);
+    }
+
+    #[test]
+    fn reset_moves_baseline_to_current_count() {
+        let mut goal = FollowGoal::new(None, 100, 0);
+        goal.apply(40, 0);
+
+        goal.reset(None);
+
+        assert_eq!(goal.start_count, 40);
+        assert_eq!(goal.progress().gained, 0);
+        assert!(!goal.apply(0, 0));
+    }
+}
@@ -0,0 +1,642 @@
+//! 팔로워 기록 저장소
+//!
+//! 팔로우/재팔로우/언팔로우 이벤트를 앱 데이터 폴더의 JSON Lines 파일에 한 줄씩 추가하고,
+//! 조회할 때마다 파일을 처음부터 읽으며 조건에 맞는 기록만 골라냅니다. (추가만 하므로 중간에 꺼져도 앞선 기록은 안전)
+//!
+//! 파일에는 최근 [`MAX_HISTORY_RECORDS`]건까지만 남기고, 이를 넘으면 오래된 기록부터 정리합니다.
+//! 메모리에는 다음 id와 기록 수만 두므로, 조회 비용은 파일 크기(최대 기록 수)에 비례합니다.
+
+use crate::chzzk::{FollowerItem, User};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+/// 앱 데이터 폴더 안의 기록 파일 이름
+const HISTORY_FILE: &str = "follower_history.jsonl";
+
+/// 한 번에 조회할 수 있는 최대 개수
+pub const MAX_QUERY_LIMIT: usize = 500;
+
+/// 기본 조회 개수
+pub const DEFAULT_QUERY_LIMIT: usize = 50;
+
+/// 파일에 남겨 두는 최대 기록 수 (넘으면 오래된 기록부터 정리)
+pub const MAX_HISTORY_RECORDS: usize = 50_000;
+
+/// CSV 열 이름 (FollowerItem/User의 serde 필드 이름과 동일하게 유지)
+pub const CSV_COLUMNS: [&str; 7] = [
+    "id",
+    "kind",
+    "userIdHash",
+    "nickname",
+    "profileImageUrl",
+    "followingSince",
+    "detectedAt",
+];
+
+/// 기록 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryEventKind {
+    Follow,
+    Refollow,
+    Unfollow,
+}
+
+/// 기록 한 건 (HistoryModal에서 팔로워 항목처럼 쓸 수 있도록 `user` 구조 유지)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryRecord {
+    pub id: u64,
+    pub kind: HistoryEventKind,
+    pub user: User,
+    #[serde(rename = "followingSince")]
+    pub following_since: String,
+    #[serde(rename = "detectedAt")]
+    pub detected_at: String,
+}
+
+/// 기록 조회 조건
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct HistoryQuery {
+    /// 이 시각(RFC 3339) 이후에 감지된 기록만
+    pub since: Option<String>,
+    /// 이 시각(RFC 3339) 이전에 감지된 기록만
+    pub until: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    /// 닉네임 부분 일치 (대소문자 무시)
+    pub nickname: Option<String>,
+    pub kind: Option<HistoryEventKind>,
+}
+
+/// 내보내기 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Json => "application/json",
+        }
+    }
+}
+
+/// 내보내기 조건
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ExportQuery {
+    pub format: Option<ExportFormat>,
+    /// 시작 (RFC 3339 또는 "YYYY-MM-DD")
+    pub from: Option<String>,
+    /// 끝 (RFC 3339 또는 "YYYY-MM-DD", 날짜면 그날 끝까지 포함)
+    pub to: Option<String>,
+    pub kind: Option<HistoryEventKind>,
+}
+
+impl ExportQuery {
+    /// 저장 대화상자/다운로드에 쓸 기본 파일 이름
+    pub fn file_name(&self) -> String {
+        let format = self.format.unwrap_or_default();
+        let range = match (self.from.as_deref(), self.to.as_deref()) {
+            (Some(from), Some(to)) => format!("{}_{}", date_part(from), date_part(to)),
+            (Some(from), None) => format!("{}_", date_part(from)),
+            (None, Some(to)) => format!("_{}", date_part(to)),
+            (None, None) => chrono::Local::now().format("%Y-%m-%d").to_string(),
+        };
+        format!("fazzk-followers-{}.{}", range, format.extension())
+    }
+}
+
+// 파일 이름에 쓸 날짜 부분 ("2024-01-31T..." -> "2024-01-31")
+fn date_part(value: &str) -> &str {
+    value.get(..10).unwrap_or(value)
+}
+
+/// 조회 결과 (최신순)
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryPage {
+    pub total: usize,
+    pub offset: usize,
+    pub items: Vec<HistoryRecord>,
+}
+
+/// 파일 기반 팔로워 기록 저장소
+pub struct HistoryStore {
+    path: PathBuf,
+    max_records: usize,
+    // 파일 접근을 한 번에 하나로 제한 (정리 중 파일 교체와 겹치지 않도록)
+    state: Mutex<StoreState>,
+}
+
+// 메모리에 두는 파일 요약
+struct StoreState {
+    next_id: u64,
+    count: usize,
+}
+
+impl HistoryStore {
+    /// 기록 파일을 열어 다음 id와 기록 수를 확인합니다. (깨진 줄은 건너뜀)
+    pub fn open(path: PathBuf) -> Self {
+        let (mut last_id, mut count) = (0, 0);
+        match read_records(&path, |_, record| {
+            last_id = record.id;
+            count += 1;
+        }) {
+            Ok(0) => {}
+            Ok(skipped) => log::warn!("[History] 잘못된 기록 {}건 무시", skipped),
+            Err(e) => log::error!("[History] {}", e),
+        }
+
+        log::info!("[History] 기록 {}건 확인: {:?}", count, path);
+        Self {
+            path,
+            max_records: MAX_HISTORY_RECORDS,
+            state: Mutex::new(StoreState {
+                next_id: last_id + 1,
+                count,
+            }),
+        }
+    }
+
+    /// 파일에 남겨 둘 최대 기록 수 변경
+    pub fn with_max_records(mut self, max_records: usize) -> Self {
+        self.max_records = max_records.max(1);
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 이벤트 기록 추가
+    pub fn append(&self, kind: HistoryEventKind, follower: &FollowerItem) -> Result<HistoryRecord, String> {
+        let mut state = self.state.lock().map_err(|e| e.to_string())?;
+
+        let record = HistoryRecord {
+            id: state.next_id,
+            kind,
+            user: follower.user.clone(),
+            following_since: follower.following_since.clone(),
+            detected_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| format!("폴더 생성 실패: {}", e))?;
+        }
+        let line = serde_json::to_string(&record).map_err(|e| format!("직렬화 실패: {}", e))?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| format!("기록 파일 열기 실패: {}", e))?;
+        writeln!(file, "{}", line).map_err(|e| format!("기록 쓰기 실패: {}", e))?;
+
+        state.next_id += 1;
+        state.count += 1;
+
+        // 추가할 때마다 다시 쓰지 않도록 최대 기록 수의 10%를 넘겼을 때 정리
+        if state.count > self.max_records + self.max_records / 10 {
+            if let Err(e) = self.compact(&mut state) {
+                log::warn!("[History] 오래된 기록 정리 실패: {}", e);
+            }
+        }
+        Ok(record)
+    }
+
+    /// 조건에 맞는 기록을 최신순으로 조회
+    pub fn query(&self, query: &HistoryQuery) -> Result<HistoryPage, String> {
+        let limit = query.limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT);
+        let offset = query.offset.unwrap_or(0);
+        let filter = RecordFilter::new(query)?;
+
+        // 오래된 순으로 읽으면서 마지막 offset + limit건만 남김
+        let keep = offset.saturating_add(limit).min(self.max_records);
+        let mut newest = std::collections::VecDeque::with_capacity(keep.min(MAX_QUERY_LIMIT));
+        let mut total = 0;
+        self.read(|record| {
+            if !filter.matches(&record) {
+                return;
+            }
+            total += 1;
+            if keep > 0 {
+                if newest.len() == keep {
+                    newest.pop_front();
+                }
+                newest.push_back(record);
+            }
+        })?;
+
+        Ok(HistoryPage {
+            total,
+            offset,
+            items: newest.into_iter().rev().skip(offset).take(limit).collect(),
+        })
+    }
+
+    /// 기간 안의 기록을 오래된 순으로 CSV 또는 JSON 문자열로 내보내기
+    pub fn export(&self, query: &ExportQuery) -> Result<String, String> {
+        let records = self.matching(&HistoryQuery {
+            since: query.from.clone(),
+            until: query.to.clone(),
+            kind: query.kind,
+            ..HistoryQuery::default()
+        })?;
+
+        match query.format.unwrap_or_default() {
+            ExportFormat::Json => serde_json::to_string_pretty(&records).map_err(|e| format!("직렬화 실패: {}", e)),
+            ExportFormat::Csv => Ok(to_csv(&records)),
+        }
+    }
+
+    /// 기간 안의 모든 기록을 오래된 순으로 (통계 계산용)
+    pub fn between(&self, from: Option<&str>, to: Option<&str>) -> Result<Vec<HistoryRecord>, String> {
+        self.matching(&HistoryQuery {
+            since: from.map(str::to_string),
+            until: to.map(str::to_string),
+            ..HistoryQuery::default()
+        })
+    }
+
+    // 조건에 맞는 모든 기록 (오래된 순)
+    fn matching(&self, query: &HistoryQuery) -> Result<Vec<HistoryRecord>, String> {
+        let filter = RecordFilter::new(query)?;
+        let mut records = Vec::new();
+        self.read(|record| {
+            if filter.matches(&record) {
+                records.push(record);
+            }
+        })?;
+        Ok(records)
+    }
+
+    // 파일 잠금을 잡고 모든 기록을 오래된 순으로 전달
+    fn read(&self, mut visit: impl FnMut(HistoryRecord)) -> Result<(), String> {
+        let _state = self.state.lock().map_err(|e| e.to_string())?;
+        read_records(&self.path, |_, record| visit(record)).map(|_| ())
+    }
+
+    // 최근 max_records건만 임시 파일에 옮겨 쓴 뒤 기록 파일과 교체
+    fn compact(&self, state: &mut StoreState) -> Result<(), String> {
+        let skip = state.count.saturating_sub(self.max_records);
+        let temp_path = self.path.with_extension("jsonl.tmp");
+        let mut temp = std::io::BufWriter::new(
+            std::fs::File::create(&temp_path).map_err(|e| format!("임시 파일 생성 실패: {}", e))?,
+        );
+
+        let (mut index, mut kept) = (0, 0);
+        let mut write_result = Ok(());
+        read_records(&self.path, |line, _| {
+            index += 1;
+            if index > skip && write_result.is_ok() {
+                write_result = writeln!(temp, "{}", line);
+                kept += 1;
+            }
+        })?;
+        write_result
+            .and_then(|_| temp.flush())
+            .map_err(|e| format!("임시 파일 쓰기 실패: {}", e))?;
+        drop(temp);
+
+        std::fs::rename(&temp_path, &self.path).map_err(|e| format!("기록 파일 교체 실패: {}", e))?;
+        log::info!("[History] 오래된 기록 {}건 정리 ({}건 유지)", state.count - kept, kept);
+        state.count = kept;
+        Ok(())
+    }
+}
+
+// 기록 파일을 처음부터 한 줄씩 읽어 원래 줄과 함께 전달 (깨진 줄은 건너뛰고 개수 반환)
+fn read_records(path: &Path, mut visit: impl FnMut(&str, HistoryRecord)) -> Result<usize, String> {
+    let file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(format!("기록 파일 읽기 실패: {}", e)),
+    };
+
+    let mut skipped = 0;
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| format!("기록 파일 읽기 실패: {}", e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<HistoryRecord>(&line) {
+            Ok(record) => visit(&line, record),
+            Err(_) => skipped += 1,
+        }
+    }
+    Ok(skipped)
+}
+
+// 조회 조건 (시각 범위는 미리 파싱)
+struct RecordFilter {
+    since: Option<chrono::DateTime<chrono::FixedOffset>>,
+    until: Option<chrono::DateTime<chrono::FixedOffset>>,
+    nickname: Option<String>,
+    kind: Option<HistoryEventKind>,
+}
+
+impl RecordFilter {
+    fn new(query: &HistoryQuery) -> Result<Self, String> {
+        Ok(Self {
+            since: parse_bound(query.since.as_deref(), false)?,
+            until: parse_bound(query.until.as_deref(), true)?,
+            nickname: query.nickname.as_ref().map(|n| n.to_lowercase()),
+            kind: query.kind,
+        })
+    }
+
+    fn matches(&self, record: &HistoryRecord) -> bool {
+        if self.kind.is_some_and(|kind| record.kind != kind) {
+            return false;
+        }
+        if self
+            .nickname
+            .as_ref()
+            .is_some_and(|n| !record.user.nickname.to_lowercase().contains(n.as_str()))
+        {
+            return false;
+        }
+        if self.since.is_none() && self.until.is_none() {
+            return true;
+        }
+        let Ok(detected_at) = chrono::DateTime::parse_from_rfc3339(&record.detected_at) else {
+            return false;
+        };
+        self.since.is_none_or(|s| detected_at >= s) && self.until.is_none_or(|u| detected_at <= u)
+    }
+}
+
+// 조회 범위 파싱 - RFC 3339 또는 날짜("YYYY-MM-DD", 로컬 시간 기준 하루의 시작/끝)
+fn parse_bound(value: Option<&str>, end_of_day: bool) -> Result<Option<chrono::DateTime<chrono::FixedOffset>>, String> {
+    let Some(value) = value.filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Ok(Some(dt));
+    }
+
+    let date = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| format!("잘못된 시각 형식: {}", value))?;
+    let time = if end_of_day {
+        chrono::NaiveTime::from_hms_milli_opt(23, 59, 59, 999)
+    } else {
+        chrono::NaiveTime::from_hms_opt(0, 0, 0)
+    }
+    .ok_or_else(|| format!("잘못된 시각 형식: {}", value))?;
+
+    date.and_time(time)
+        .and_local_timezone(chrono::Local)
+        .earliest()
+        .map(|dt| Some(dt.fixed_offset()))
+        .ok_or_else(|| format!("잘못된 시각 형식: {}", value))
+}
+
+// CSV 변환 (열 이름은 FollowerItem/User의 serde 필드 이름과 동일)
+// 엑셀에서 한글이 깨지지 않도록 UTF-8 BOM을 붙임
+fn to_csv(records: &[HistoryRecord]) -> String {
+    let mut csv = String::from("\u{feff}");
+    csv.push_str(&CSV_COLUMNS.join(","));
+    csv.push_str("\r\n");
+
+    for record in records {
+        let kind = serde_json::to_value(record.kind)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        let fields = [
+            record.id.to_string(),
+            kind,
+            record.user.user_id_hash.clone(),
+            record.user.nickname.clone(),
+            record.user.profile_image_url.clone().unwrap_or_default(),
+            record.following_since.clone(),
+            record.detected_at.clone(),
+        ];
+        let row: Vec<String> = fields.iter().map(|field| escape_csv(field)).collect();
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+// 쉼표/따옴표/줄바꿈이 있으면 따옴표로 감싸고, 수식으로 해석될 수 있는 값은 앞에 '를 붙임
+fn escape_csv(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// 기록 파일 경로 (앱 데이터 폴더)
+pub fn history_path(app_handle: &AppHandle) -> Option<PathBuf> {
+    match app_handle.path().app_data_dir() {
+        Ok(dir) => Some(dir.join(HISTORY_FILE)),
+        Err(e) => {
+            log::error!("[History] 앱 데이터 폴더를 찾을 수 없습니다: {}", e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chzzk::test_support::follower;
+
+    fn record(id: u64, nickname: &str, detected_at: &str) -> HistoryRecord {
+        let follower = follower(&format!("hash-{}", id), nickname);
+        HistoryRecord {
+            id,
+            kind: HistoryEventKind::Follow,
+            user: follower.user,
+            following_since: follower.following_since,
+            detected_at: detected_at.to_string(),
+        }
+    }
+
+    // 주어진 줄로 기록 파일을 만들어 열기
+    fn store_with_lines(name: &str, lines: &[String]) -> HistoryStore {
+        let dir = std::env::temp_dir().join(format!("fazzk-history-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(HISTORY_FILE);
+        std::fs::write(&path, lines.join("\n")).unwrap();
+        HistoryStore::open(path)
+    }
+
+    fn remove(store: &HistoryStore) {
+        let _ = std::fs::remove_dir_all(store.path().parent().unwrap());
+    }
+
+    #[test]
+    fn open_skips_malformed_lines() {
+        let lines = vec![
+            serde_json::to_string(&record(1, "a", "2024-01-01T00:00:00Z")).unwrap(),
+            "{ not json".to_string(),
+            String::new(),
+            r#"{"id":2,"kind":"unknown"}"#.to_string(),
+            serde_json::to_string(&record(3, "b", "2024-01-02T00:00:00Z")).unwrap(),
+        ];
+        let store = store_with_lines("malformed", &lines);
+
+        let page = store.query(&HistoryQuery::default()).unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(page.items.iter().map(|r| r.id).collect::<Vec<_>>(), vec![3, 1]);
+
+        // 깨진 줄이 있어도 다음 id로 이어서 추가
+        let follower = follower("hash-0", "c");
+        assert_eq!(store.append(HistoryEventKind::Unfollow, &follower).unwrap().id, 4);
+        remove(&store);
+    }
+
+    #[test]
+    fn query_pages_within_bounds() {
+        let lines: Vec<String> = (1..=MAX_QUERY_LIMIT as u64 + 10)
+            .map(|id| serde_json::to_string(&record(id, "user", "2024-01-01T00:00:00Z")).unwrap())
+            .collect();
+        let store = store_with_lines("paging", &lines);
+
+        let page = store.query(&HistoryQuery::default()).unwrap();
+        assert_eq!(page.total, lines.len());
+        assert_eq!(page.items.len(), DEFAULT_QUERY_LIMIT);
+        assert_eq!(page.items[0].id, lines.len() as u64);
+
+        // 최대 개수보다 많이 요청하면 최대 개수까지만
+        let page = store
+            .query(&HistoryQuery {
+                limit: Some(MAX_QUERY_LIMIT * 2),
+                ..HistoryQuery::default()
+            })
+            .unwrap();
+        assert_eq!(page.items.len(), MAX_QUERY_LIMIT);
+
+        let page = store
+            .query(&HistoryQuery {
+                limit: Some(5),
+                offset: Some(lines.len() - 2),
+                ..HistoryQuery::default()
+            })
+            .unwrap();
+        assert_eq!(page.items.iter().map(|r| r.id).collect::<Vec<_>>(), vec![2, 1]);
+
+        let page = store
+            .query(&HistoryQuery {
+                offset: Some(lines.len() + 1),
+                ..HistoryQuery::default()
+            })
+            .unwrap();
+        assert!(page.items.is_empty());
+        assert_eq!(page.total, lines.len());
+        remove(&store);
+    }
+
+    #[test]
+    fn old_records_are_dropped_past_the_limit() {
+        let store = store_with_lines("retention", &[]).with_max_records(10);
+        let follower = follower("hash-0", "a");
+
+        // 최대 기록 수의 10%를 넘기면 최근 10건만 남김
+        for _ in 0..11 {
+            store.append(HistoryEventKind::Follow, &follower).unwrap();
+        }
+        assert_eq!(store.query(&HistoryQuery::default()).unwrap().total, 11);
+        store.append(HistoryEventKind::Follow, &follower).unwrap();
+
+        let page = store.query(&HistoryQuery::default()).unwrap();
+        assert_eq!(page.total, 10);
+        assert_eq!(page.items.first().map(|r| r.id), Some(12));
+        assert_eq!(page.items.last().map(|r| r.id), Some(3));
+        assert_eq!(std::fs::read_to_string(store.path()).unwrap().lines().count(), 10);
+
+        // 정리 후에도 id는 이어짐
+        assert_eq!(store.append(HistoryEventKind::Unfollow, &follower).unwrap().id, 13);
+        remove(&store);
+    }
+
+    #[test]
+    fn parse_bound_accepts_rfc3339_and_dates() {
+        assert_eq!(parse_bound(None, false).unwrap(), None);
+        assert_eq!(parse_bound(Some(""), true).unwrap(), None);
+
+        let exact = parse_bound(Some("2024-01-31T12:00:00+09:00"), true).unwrap().unwrap();
+        assert_eq!(exact.to_rfc3339(), "2024-01-31T12:00:00+09:00");
+
+        // 날짜만 주면 로컬 시간 기준 하루의 시작/끝
+        let start = parse_bound(Some("2024-01-31"), false).unwrap().unwrap();
+        let end = parse_bound(Some("2024-01-31"), true).unwrap().unwrap();
+        assert_eq!(start.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string(), "2024-01-31 00:00:00");
+        assert_eq!(end.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S%.3f").to_string(), "2024-01-31 23:59:59.999");
+
+        assert!(parse_bound(Some("31/01/2024"), false).is_err());
+    }
+
+    #[test]
+    fn escape_csv_quotes_special_characters() {
+        assert_eq!(escape_csv("plain"), "plain");
+        assert_eq!(escape_csv("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_csv("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(escape_csv("cr\rlf"), "\"cr\rlf\"");
+        // 수식으로 해석될 수 있는 값
+        assert_eq!(escape_csv("=SUM(A1)"), "'=SUM(A1)");
+        assert_eq!(escape_csv("-1,2"), "\"'-1,2\"");
+    }
+
+    #[test]
+    fn export_writes_range_oldest_first() {
+        let lines = vec![
+            serde_json::to_string(&record(1, "old", "2024-01-01T00:00:00Z")).unwrap(),
+            serde_json::to_string(&record(2, "a,\"b\"\nc", "2024-02-01T00:00:00Z")).unwrap(),
+            serde_json::to_string(&record(3, "new", "2024-03-01T00:00:00Z")).unwrap(),
+        ];
+        let store = store_with_lines("export", &lines);
+        let range = ExportQuery {
+            from: Some("2024-01-15T00:00:00Z".to_string()),
+            to: Some("2024-03-15T00:00:00Z".to_string()),
+            ..ExportQuery::default()
+        };
+
+        let csv = store.export(&range).unwrap();
+        let expected = format!(
+            "\u{feff}{}\r\n2,follow,hash-2,\"a,\"\"b\"\"\nc\",,2024-01-01 09:00:00,2024-02-01T00:00:00Z\r\n3,follow,hash-3,new,,2024-01-01 09:00:00,2024-03-01T00:00:00Z\r\n",
+            CSV_COLUMNS.join(",")
+        );
+        assert_eq!(csv, expected);
+
+        let json = store
+            .export(&ExportQuery {
+                format: Some(ExportFormat::Json),
+                ..range.clone()
+            })
+            .unwrap();
+        let records: Vec<HistoryRecord> = serde_json::from_str(&json).unwrap();
+        assert_eq!(records.iter().map(|r| r.id).collect::<Vec<_>>(), vec![2, 3]);
+
+        assert!(store
+            .export(&ExportQuery {
+                from: Some("yesterday".to_string()),
+                ..ExportQuery::default()
+            })
+            .is_err());
+        remove(&store);
+    }
+}
@@ -1,7 +1,17 @@
+pub mod alert_queue;
+pub mod baseline;
 pub mod chzzk;
+pub mod follow_history;
+pub mod follower_diff;
+pub mod goal;
+pub mod history;
+pub mod monitor;
 pub mod server;
 pub mod state;
+pub mod stats;
+pub mod text_output;
 pub mod updater;
+pub mod watchlist;
 pub mod websocket;
 
 use state::AppState;
@@ -35,6 +45,7 @@ fn update_login_state(
     state: &AppState,
     cookie_data: state::CookieData,
     user_id_hash: String,
+    nickname: String,
 ) -> Result<(), String> {
     state.cookies
         .lock()
@@ -50,9 +61,30 @@ fn update_login_state(
         .map_err(|e| format!("사용자 ID 잠금 실패: {}", e))?
         .replace(user_id_hash);
     
+    state.nickname
+        .lock()
+        .map_err(|e| format!("닉네임 잠금 실패: {}", e))?
+        .replace(nickname);
+    
+    // 세션 만료로 멈춘 모니터 재개
+    state.session_renewed.notify_one();
+    
     Ok(())
 }
 
+/// 치지직 API 오류를 사용자에게 보여줄 메시지로 변환합니다.
+fn describe_chzzk_error(e: &chzzk::ChzzkError) -> String {
+    use chzzk::ChzzkError;
+
+    match e {
+        ChzzkError::Unauthorized => "쿠키가 만료되었거나 올바르지 않습니다. 다시 로그인해주세요".to_string(),
+        ChzzkError::Network(_) => format!("네트워크 연결을 확인해주세요 ({})", e),
+        ChzzkError::RateLimited { .. } => format!("요청이 너무 많습니다. 잠시 후 다시 시도해주세요 ({})", e),
+        ChzzkError::ServerError(_) => format!("치지직 서버 오류입니다. 잠시 후 다시 시도해주세요 ({})", e),
+        ChzzkError::Decode(_) | ChzzkError::ApiCode { .. } => format!("치지직 응답을 처리할 수 없습니다 ({})", e),
+    }
+}
+
 /// 앱 시작 시 저장된 쿠키를 로드하고 검증합니다.
 #[tauri::command]
 async fn check_auto_login(
@@ -99,7 +131,7 @@ async fn check_auto_login(
         nid_ses: nid_ses_str.clone(),
     };
 
-    match chzzk::get_profile_id(&state.client, &cookie_data).await {
+    match state.chzzk.get_profile_id(&cookie_data).await {
         Ok((user_id_hash, nickname)) => {
             println!(
                 "[Command] Auto-login successful: {} ({})",
@@ -107,7 +139,7 @@ async fn check_auto_login(
             );
 
             // 3. Update Global State
-            update_login_state(&state, cookie_data, user_id_hash.clone())?;
+            update_login_state(&state, cookie_data, user_id_hash.clone(), nickname.clone())?;
 
             // 4. Return user info
             Ok(serde_json::json!({
@@ -118,11 +150,13 @@ async fn check_auto_login(
         }
         Err(e) => {
             println!("[Command] Auto-login verification failed: {}", e);
-            // Clear invalid cookies
-            let _ = store.delete("NID_AUT");
-            let _ = store.delete("NID_SES");
-            let _ = store.save();
-            Err(format!("자동 로그인 실패: {}", e))
+            // 쿠키가 만료된 경우에만 삭제 (네트워크 오류 등은 다음 실행 때 다시 시도)
+            if e.is_auth() {
+                let _ = store.delete("NID_AUT");
+                let _ = store.delete("NID_SES");
+                let _ = store.save();
+            }
+            Err(format!("자동 로그인 실패: {}", describe_chzzk_error(&e)))
         }
     }
 }
@@ -186,12 +220,12 @@ async fn manual_login(
         nid_ses: nid_ses.clone(),
     };
 
-    match chzzk::get_profile_id(&state.client, &cookie_data).await {
+    match state.chzzk.get_profile_id(&cookie_data).await {
         Ok((user_id_hash, nickname)) => {
             println!("[Command] Login verified: {} ({})", nickname, user_id_hash);
 
             // 2. Update Global State
-            update_login_state(&state, cookie_data, user_id_hash.clone())?;
+            update_login_state(&state, cookie_data, user_id_hash.clone(), nickname.clone())?;
 
             // 3. Emit Success Event
             use tauri::Emitter;
@@ -208,7 +242,7 @@ async fn manual_login(
         }
         Err(e) => {
             println!("[Command] Login verification failed: {}", e);
-            Err(format!("로그인 검증 실패: {}", e))
+            Err(format!("로그인 검증 실패: {}", describe_chzzk_error(&e)))
         }
     }
 }
@@ -246,6 +280,16 @@ pub fn run() {
             let handle = app.handle().clone();
             let state = server_state.clone();
 
+            // 팔로워 기록 저장소 열기
+            if let Some(path) = history::history_path(&handle) {
+                let _ = state.history.set(history::HistoryStore::open(path));
+            }
+
+            // 저장된 팔로우 목표 불러오기
+            if let Ok(mut goal) = state.goal.lock() {
+                *goal = goal::load(&handle);
+            }
+
             // scripts 폴더 및 파일 생성
             tauri::async_runtime::spawn(async move {
                 if let Err(e) = ensure_scripts_folder().await {
@@ -311,6 +355,13 @@ pub fn run() {
             get_server_port,
             get_app_version,
             get_app_dir,
+            get_monitor_status,
+            pause_monitor,
+            resume_monitor,
+            restart_monitor,
+            get_follower_history,
+            export_follower_history,
+            get_follower_stats,
             updater::check_for_updates,
             updater::open_download_page,
             updater::download_and_install_update
@@ -325,6 +376,86 @@ async fn get_server_port(state: tauri::State<'_, Arc<AppState>>) -> Result<u16,
     Ok(*port)
 }
 
+#[tauri::command]
+fn get_follower_history(
+    state: tauri::State<'_, Arc<AppState>>,
+    query: Option<history::HistoryQuery>,
+) -> Result<history::HistoryPage, String> {
+    let store = state.history.get().ok_or("기록 저장소를 사용할 수 없습니다")?;
+    store.query(&query.unwrap_or_default())
+}
+
+/// 팔로워 기록을 저장 대화상자에서 고른 경로로 내보냅니다. 취소하면 None을 반환합니다.
+#[tauri::command]
+async fn export_follower_history(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, Arc<AppState>>,
+    query: Option<history::ExportQuery>,
+) -> Result<Option<String>, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let query = query.unwrap_or_default();
+    let store = state.history.get().ok_or("기록 저장소를 사용할 수 없습니다")?;
+    let content = store.export(&query)?;
+
+    let format = query.format.unwrap_or_default();
+    let (filter_name, extension) = match format {
+        history::ExportFormat::Csv => ("CSV", format.extension()),
+        history::ExportFormat::Json => ("JSON", format.extension()),
+    };
+
+    // async 커맨드 안에서 blocking 대화상자를 쓰면 런타임 워커가 멈추므로 콜백 결과를 채널로 받음
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    app.dialog()
+        .file()
+        .add_filter(filter_name, &[extension])
+        .set_file_name(query.file_name())
+        .save_file(move |file_path| {
+            let _ = tx.send(file_path);
+        });
+
+    let Some(file_path) = rx.await.map_err(|e| e.to_string())? else {
+        return Ok(None);
+    };
+
+    let path = file_path.into_path().map_err(|e| e.to_string())?;
+    tokio::fs::write(&path, content)
+        .await
+        .map_err(|e| format!("파일 저장 실패: {}", e))?;
+    log::info!("[History] 기록 내보내기 완료: {:?}", path);
+
+    Ok(Some(path.to_string_lossy().to_string()))
+}
+
+#[tauri::command]
+fn get_follower_stats(
+    state: tauri::State<'_, Arc<AppState>>,
+    query: Option<stats::StatsQuery>,
+) -> Result<stats::FollowerStats, String> {
+    let store = state.history.get().ok_or("기록 저장소를 사용할 수 없습니다")?;
+    stats::compute(store, &query.unwrap_or_default())
+}
+
+#[tauri::command]
+fn get_monitor_status(state: tauri::State<'_, Arc<AppState>>) -> monitor::MonitorStatus {
+    state.monitor.status()
+}
+
+#[tauri::command]
+fn pause_monitor(state: tauri::State<'_, Arc<AppState>>) -> Result<(), String> {
+    state.monitor.send(monitor::MonitorCommand::Pause)
+}
+
+#[tauri::command]
+fn resume_monitor(state: tauri::State<'_, Arc<AppState>>) -> Result<(), String> {
+    state.monitor.send(monitor::MonitorCommand::Resume)
+}
+
+#[tauri::command]
+fn restart_monitor(state: tauri::State<'_, Arc<AppState>>) -> Result<(), String> {
+    state.monitor.send(monitor::MonitorCommand::Restart)
+}
+
 
 
 #[tauri::command]
@@ -0,0 +1,1077 @@
+//! 팔로워 모니터 감독(supervisor)
+//!
+//! 팔로워 폴링 작업을 상태 머신으로 관리합니다. 작업이 연속 에러로 끝나도 잠시 후 다시 시작하며,
+//! Tauri 커맨드와 HTTP 라우트로 상태 조회 및 일시정지/재개/재시작을 제공합니다.
+//! 상태가 바뀔 때마다 WebSocket으로 `monitor_status` 메시지를 브로드캐스트합니다.
+//! 설정, 저장 파일, 앱 이벤트는 [`MonitorHost`]를 거치므로 `AppHandle` 없이도 실행할 수 있습니다.
+
+use crate::baseline::{self, FollowerBaseline, OfflineFollowMode};
+use crate::chzzk::{self, ChzzkError, FollowerItem, FollowerPageQuery};
+use crate::follow_history::{CooldownMode, CooldownPolicy, FollowDecision, DEFAULT_REFOLLOW_COOLDOWN};
+use crate::follower_diff::{FollowerDiffEngine, FollowerEvent};
+use crate::goal::{self, FollowGoal};
+use crate::history::HistoryEventKind;
+use crate::stats::{self, StatsQuery};
+use crate::state::{self, AppState, MAX_RECENT_FOLLOWERS};
+use crate::text_output;
+use crate::watchlist::{self, WatchEntry, WatchList};
+use crate::websocket::{WSEvent, WSManager, WSMessage};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{broadcast, mpsc, watch};
+
+/// 작업을 재시작하기 전까지 허용하는 연속 에러 횟수
+const MAX_ERRORS: u32 = 10;
+
+/// 기본 폴링 주기 (설정이 없을 때)
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 허용하는 폴링 주기 범위 (설정 화면과 동일)
+const MIN_POLL_INTERVAL_SECS: u64 = 5;
+const MAX_POLL_INTERVAL_SECS: u64 = 300;
+
+/// 에러 백오프 최대 대기 시간 (폴링 주기가 더 길면 폴링 주기 사용)
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// 작업이 연속 에러로 끝난 뒤 재시작까지 대기 시간
+const RESTART_DELAY: Duration = Duration::from_secs(60);
+
+/// 통계 전달 최소 간격 (기록 파일 전체를 다시 읽으므로 폴링마다 보내지 않음)
+const STATS_BROADCAST_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 모니터 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MonitorState {
+    /// 폴링할 조건이 아님 (로그인 전, 연결된 오버레이 없음)
+    Idle,
+    /// 기준 팔로워 목록 수집 중
+    Initializing,
+    /// 정상 폴링 중
+    Running,
+    /// 에러 또는 요청 한도 초과로 대기 중
+    Backoff,
+    /// 세션 만료로 재로그인 대기 중
+    Suspended,
+    /// 사용자가 일시정지함
+    Stopped,
+}
+
+/// 모니터 상태 스냅샷
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonitorStatus {
+    pub state: MonitorState,
+    pub reason: Option<String>,
+    #[serde(rename = "errorCount")]
+    pub error_count: u32,
+    pub restarts: u32,
+    #[serde(rename = "lastPollAt")]
+    pub last_poll_at: Option<String>,
+    #[serde(rename = "changedAt")]
+    pub changed_at: String,
+    // 요청 한도 초과로 대기 중일 때 다시 시도까지 대기 시간 (초)
+    #[serde(rename = "retryAfterSecs")]
+    pub retry_after_secs: Option<u64>,
+    // 앱 실행 후 followingSince 파싱에 실패한 횟수 (0이 아니면 치지직 응답 형식이 바뀌었을 수 있음)
+    #[serde(rename = "timestampParseFailures")]
+    pub timestamp_parse_failures: u64,
+}
+
+impl Default for MonitorStatus {
+    fn default() -> Self {
+        Self {
+            state: MonitorState::Idle,
+            reason: None,
+            error_count: 0,
+            restarts: 0,
+            last_poll_at: None,
+            changed_at: chrono::Utc::now().to_rfc3339(),
+            retry_after_secs: None,
+            timestamp_parse_failures: 0,
+        }
+    }
+}
+
+/// 모니터 제어 명령
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitorCommand {
+    Pause,
+    Resume,
+    Restart,
+}
+
+/// AppState에 보관하는 모니터 상태/제어 핸들
+pub struct MonitorHandle {
+    status_tx: watch::Sender<MonitorStatus>,
+    command_tx: mpsc::UnboundedSender<MonitorCommand>,
+    command_rx: Mutex<Option<mpsc::UnboundedReceiver<MonitorCommand>>>,
+}
+
+impl Default for MonitorHandle {
+    fn default() -> Self {
+        let (status_tx, _) = watch::channel(MonitorStatus::default());
+        let (command_tx, command_rx) = mpsc::unbounded_channel();
+
+        Self {
+            status_tx,
+            command_tx,
+            command_rx: Mutex::new(Some(command_rx)),
+        }
+    }
+}
+
+impl MonitorHandle {
+    /// 현재 상태
+    pub fn status(&self) -> MonitorStatus {
+        self.status_tx.borrow().clone()
+    }
+
+    /// 상태 변경 구독 (상태 또는 사유가 바뀔 때만 알림)
+    pub fn subscribe(&self) -> watch::Receiver<MonitorStatus> {
+        self.status_tx.subscribe()
+    }
+
+    /// 감독 태스크에 명령 전달
+    pub fn send(&self, command: MonitorCommand) -> Result<(), String> {
+        self.command_tx
+            .send(command)
+            .map_err(|_| "모니터가 실행 중이 아닙니다".to_string())
+    }
+
+    fn set_state(&self, state: MonitorState, reason: Option<String>) {
+        self.set_state_with_retry(state, reason, None);
+    }
+
+    // 다시 시도할 때까지 대기 시간과 함께 상태 변경
+    fn set_state_with_retry(&self, state: MonitorState, reason: Option<String>, retry_after: Option<Duration>) {
+        let retry_after_secs = retry_after.map(|wait| wait.as_secs());
+        self.status_tx.send_if_modified(|status| {
+            if status.state == state && status.reason == reason && status.retry_after_secs == retry_after_secs {
+                return false;
+            }
+            log::info!("[FollowerMonitor] 상태 변경: {:?} -> {:?} ({:?})", status.state, state, reason);
+            status.state = state;
+            status.reason = reason;
+            status.retry_after_secs = retry_after_secs;
+            status.changed_at = chrono::Utc::now().to_rfc3339();
+            true
+        });
+    }
+
+    // 에러 횟수/폴링 시각은 자주 바뀌므로 구독자에게 알리지 않고 값만 갱신
+    fn set_error_count(&self, error_count: u32) {
+        self.status_tx.send_if_modified(|status| {
+            status.error_count = error_count;
+            false
+        });
+    }
+
+    fn mark_polled(&self) {
+        self.status_tx.send_if_modified(|status| {
+            status.last_poll_at = Some(chrono::Utc::now().to_rfc3339());
+            false
+        });
+    }
+
+    // 파싱 실패 횟수 갱신 (늘었으면 경고만 남기고 구독자에게는 알리지 않음)
+    fn record_parse_failures(&self, failures: u64) {
+        self.status_tx.send_if_modified(|status| {
+            if failures > status.timestamp_parse_failures {
+                log::warn!(
+                    "[FollowerMonitor] followingSince 파싱 실패 {}회 추가 (누적 {}회), 팔로우 시각 없이 비교합니다",
+                    failures - status.timestamp_parse_failures,
+                    failures
+                );
+            }
+            status.timestamp_parse_failures = failures;
+            false
+        });
+    }
+
+    fn record_restart(&self) {
+        self.status_tx.send_if_modified(|status| {
+            status.restarts += 1;
+            false
+        });
+    }
+
+    fn take_commands(&self) -> Option<mpsc::UnboundedReceiver<MonitorCommand>> {
+        self.command_rx.lock().ok()?.take()
+    }
+}
+
+/// 모니터가 설정, 저장 파일, 앱 이벤트에 접근하는 통로 (앱에서는 `AppHandle`)
+pub trait MonitorHost: Send + Sync + 'static {
+    /// 폴링 주기
+    fn polling_interval(&self) -> Duration;
+    /// 관심 팔로워 목록 (폴링마다 다시 읽음)
+    fn watch_list(&self) -> WatchList;
+    /// 언팔로우 이벤트 전송 여부
+    fn unfollow_events_enabled(&self) -> bool;
+    /// 재팔로우 쿨다운 설정
+    fn cooldown_policy(&self) -> CooldownPolicy;
+    /// 앱이 꺼져 있는 동안 들어온 팔로우 처리 방식
+    fn offline_follow_mode(&self) -> OfflineFollowMode;
+    /// 기준점 파일 경로 (None이면 저장/복원하지 않음)
+    fn baseline_path(&self) -> Option<PathBuf>;
+    /// 바뀐 팔로우 목표 저장
+    fn save_goal(&self, goal: &FollowGoal) -> Result<(), String>;
+    /// OBS 텍스트 소스용 파일 갱신
+    fn write_text_outputs(&self, app_state: &AppState);
+    /// 앱 화면으로 이벤트 전달
+    fn emit(&self, event: &str, payload: serde_json::Value) -> Result<(), String>;
+}
+
+impl MonitorHost for AppHandle {
+    fn polling_interval(&self) -> Duration {
+        read_polling_interval(self)
+    }
+
+    fn watch_list(&self) -> WatchList {
+        watchlist::read_watch_list(self)
+    }
+
+    fn unfollow_events_enabled(&self) -> bool {
+        read_enable_unfollow_events(self)
+    }
+
+    fn cooldown_policy(&self) -> CooldownPolicy {
+        read_cooldown_policy(self)
+    }
+
+    fn offline_follow_mode(&self) -> OfflineFollowMode {
+        baseline::read_offline_follow_mode(self)
+    }
+
+    fn baseline_path(&self) -> Option<PathBuf> {
+        baseline::baseline_path(self)
+    }
+
+    fn save_goal(&self, goal: &FollowGoal) -> Result<(), String> {
+        goal::save(self, Some(goal))
+    }
+
+    fn write_text_outputs(&self, app_state: &AppState) {
+        write_text_outputs(app_state, self);
+    }
+
+    fn emit(&self, event: &str, payload: serde_json::Value) -> Result<(), String> {
+        Emitter::emit(self, event, payload).map_err(|e| e.to_string())
+    }
+}
+
+/// 팔로워 모니터 감독 태스크 시작
+pub fn spawn_monitor<H: MonitorHost>(app_state: Arc<AppState>, ws_manager: WSManager, host: H) {
+    let Some(mut commands) = app_state.monitor.take_commands() else {
+        log::error!("[FollowerMonitor] 감독 태스크가 이미 실행 중입니다");
+        return;
+    };
+
+    // 상태 변경 시 WebSocket 브로드캐스트
+    let mut status_rx = app_state.monitor.subscribe();
+    let status_ws = ws_manager.clone();
+    tokio::spawn(async move {
+        while status_rx.changed().await.is_ok() {
+            let status = status_rx.borrow_and_update().clone();
+            status_ws.broadcast_monitor_status(status).await;
+        }
+    });
+
+    log::info!("[FollowerMonitor] Starting supervised monitoring with compression & caching");
+
+    tokio::spawn(async move {
+        let monitor = &app_state.monitor;
+        let mut paused = false;
+
+        loop {
+            if paused {
+                monitor.set_state(MonitorState::Stopped, Some("paused".to_string()));
+                match commands.recv().await {
+                    Some(MonitorCommand::Resume | MonitorCommand::Restart) => paused = false,
+                    Some(MonitorCommand::Pause) => continue,
+                    None => break,
+                }
+            }
+
+            let worker = run_worker(&app_state, &ws_manager, &host);
+            tokio::pin!(worker);
+
+            // 작업이 끝나거나 명령이 올 때까지 대기 (작업 future를 버리면 즉시 중단됨)
+            let failed = loop {
+                tokio::select! {
+                    () = &mut worker => break true,
+                    command = commands.recv() => match command {
+                        Some(MonitorCommand::Pause) => {
+                            paused = true;
+                            break false;
+                        }
+                        Some(MonitorCommand::Restart) => break false,
+                        // 이미 실행 중
+                        Some(MonitorCommand::Resume) => {}
+                        None => return,
+                    },
+                }
+            };
+
+            if failed {
+                log::error!("[FollowerMonitor] 최대 에러 횟수 초과, {}초 후 재시작", RESTART_DELAY.as_secs());
+                monitor.set_state(MonitorState::Backoff, Some("too many errors, restarting".to_string()));
+
+                tokio::select! {
+                    () = tokio::time::sleep(RESTART_DELAY) => {}
+                    command = commands.recv() => match command {
+                        Some(MonitorCommand::Pause) => paused = true,
+                        Some(_) => {}
+                        None => return,
+                    },
+                }
+            }
+
+            if !paused {
+                log::info!("[FollowerMonitor] 모니터링 재시작");
+                monitor.record_restart();
+            }
+        }
+
+        log::warn!("[FollowerMonitor] 모니터링 종료");
+    });
+}
+
+// 팔로워 폴링 작업 (압축 저장 + API 캐싱 적용), 연속 에러가 MAX_ERRORS에 도달하면 반환
+async fn run_worker<H: MonitorHost>(app_state: &Arc<AppState>, ws_manager: &WSManager, host: &H) {
+    let monitor = &app_state.monitor;
+    let mut engine = new_engine();
+    let mut error_count: u32 = 0;
+    let mut baseline_checked = false;
+    let mut saved_baseline: Option<Vec<(String, String)>> = None;
+    // 기준점을 만든 채널 (다른 계정으로 다시 로그인하면 바뀜)
+    let mut channel_id: Option<String> = None;
+    let mut poll_interval = host.polling_interval();
+    let mut settings_rx = ws_manager.subscribe();
+    let mut stats_throttle = StatsThrottle::default();
+    log::info!("[FollowerMonitor] 폴링 주기: {}초", poll_interval.as_secs());
+
+    // 재시작 시 이전 작업의 캐시를 쓰지 않도록 비움
+    if let Ok(mut cache) = app_state.api_cache.lock() {
+        cache.followers_cache = None;
+    }
+
+    loop {
+        monitor.set_error_count(error_count);
+
+        // 설정한 주기마다 팔로워 확인 (에러 시 지수 백오프), 대기 중 주기가 바뀌면 즉시 반영
+        let started = tokio::time::Instant::now();
+        loop {
+            let deadline = started + next_sleep_duration(poll_interval, error_count);
+
+            tokio::select! {
+                () = tokio::time::sleep_until(deadline) => break,
+                message = settings_rx.recv() => match message {
+                    Ok(WSEvent { message: WSMessage::SettingsUpdated { settings }, .. }) => {
+                        if let Some(interval) = parse_polling_interval(&settings) {
+                            if interval != poll_interval {
+                                log::info!(
+                                    "[FollowerMonitor] 폴링 주기 변경: {}초 -> {}초",
+                                    poll_interval.as_secs(),
+                                    interval.as_secs()
+                                );
+                                poll_interval = interval;
+                            }
+                        }
+                    }
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(broadcast::error::RecvError::Closed) => {
+                        tokio::time::sleep_until(deadline).await;
+                        break;
+                    }
+                },
+            }
+        }
+
+        // 최대 에러 횟수 초과 시 감독 태스크가 재시작
+        if error_count >= MAX_ERRORS {
+            return;
+        }
+
+        // WebSocket 클라이언트가 있는 경우에만 모니터링
+        if ws_manager.client_count().await == 0 {
+            monitor.set_state(MonitorState::Idle, Some("no overlay clients".to_string()));
+            continue;
+        }
+
+        // 쿠키와 사용자 ID 확인
+        let credentials = {
+            let cookies = app_state.cookies.lock().ok().and_then(|c| c.clone());
+            let user_id_hash = app_state.user_id_hash.lock().ok().and_then(|h| h.clone());
+            cookies.zip(user_id_hash)
+        };
+        // 인증 정보가 없으면 건너뛰기 (에러 카운트 증가 안함)
+        let Some((cookies, user_id_hash)) = credentials else {
+            monitor.set_state(MonitorState::Idle, Some("not logged in".to_string()));
+            continue;
+        };
+
+        // 세션 갱신 등으로 다른 계정에 로그인했으면 이전 채널의 팔로워 목록과 비교하지 않도록 처음부터 다시 시작
+        if channel_id.as_deref().is_some_and(|id| id != user_id_hash) {
+            log::info!("[FollowerMonitor] 로그인 계정 변경 감지, 기준점 초기화");
+            engine = new_engine();
+            baseline_checked = false;
+            saved_baseline = None;
+            app_state.reset_channel_state();
+        }
+        channel_id = Some(user_id_hash.clone());
+
+        if !engine.is_initialized() {
+            monitor.set_state(MonitorState::Initializing, None);
+
+            // 저장된 기준점이 있으면 이어서 비교 (꺼져 있는 동안의 팔로우를 첫 조회에서 알림)
+            if !baseline_checked {
+                baseline_checked = true;
+                if let Some(restored) = restore_baseline(app_state, host, &user_id_hash) {
+                    engine = restored;
+                    sync_engine_state(app_state, &engine);
+                }
+            }
+        }
+
+        // API 캐시 확인 먼저
+        let cached_followers = app_state
+            .api_cache
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get_cached_followers().cloned());
+
+        let current_followers = if let Some(followers) = cached_followers {
+            log::debug!("[FollowerMonitor] Using cached followers data");
+            followers
+        } else {
+            // 캐시 미스 - 마지막으로 확인한 팔로워까지 페이지를 넘기며 API 호출
+            let last_seen = engine.snapshot().and_then(|s| s.first()).cloned();
+            let query = FollowerPageQuery {
+                // 기준점이 없으면 첫 페이지만 조회
+                max_pages: if last_seen.is_some() { chzzk::DEFAULT_MAX_PAGES } else { 1 },
+                until: last_seen,
+                ..FollowerPageQuery::default()
+            };
+
+            match app_state.chzzk.get_followers_until(&cookies, &user_id_hash, &query).await {
+                Ok(followers) => {
+                    log::debug!("[FollowerMonitor] API call successful, caching {} followers", followers.len());
+
+                    if let Ok(mut cache) = app_state.api_cache.lock() {
+                        cache.cache_followers(followers.clone());
+                    }
+
+                    // 성공 시 에러 카운트 리셋
+                    error_count = 0;
+                    monitor.mark_polled();
+
+                    followers
+                }
+                Err(ChzzkError::Unauthorized) => {
+                    // 세션 만료는 재시도해도 해결되지 않으므로 에러 카운트 대신 재로그인 대기
+                    monitor.set_state(MonitorState::Suspended, Some("session expired".to_string()));
+                    handle_session_expired(app_state, ws_manager, host).await;
+                    error_count = 0;
+                    continue;
+                }
+                Err(ChzzkError::RateLimited { retry_after }) => {
+                    // 요청 한도 초과는 서버가 알려준 시간만큼 쉬고 다시 시도 (에러 카운트 증가 안함)
+                    let wait = rate_limit_wait(retry_after);
+                    if retry_after.is_some_and(|requested| requested > wait) {
+                        log::warn!(
+                            "[FollowerMonitor] 요청 한도 초과, Retry-After {:?}가 너무 길어 {}초만 대기",
+                            retry_after,
+                            wait.as_secs()
+                        );
+                    } else {
+                        log::warn!("[FollowerMonitor] 요청 한도 초과, {}초 대기", wait.as_secs());
+                    }
+                    monitor.set_state_with_retry(MonitorState::Backoff, Some("rate limited".to_string()), Some(wait));
+                    tokio::time::sleep(wait).await;
+                    continue;
+                }
+                Err(e) => {
+                    error_count += 1;
+                    if matches!(e, ChzzkError::Decode(_) | ChzzkError::ApiCode { .. }) {
+                        log::error!("[FollowerMonitor] API 응답 형식 오류 ({}/{}): {}", error_count, MAX_ERRORS, e);
+                    } else {
+                        log::warn!("[FollowerMonitor] 팔로워 조회 실패 ({}/{}): {}", error_count, MAX_ERRORS, e);
+                    }
+
+                    // 에러가 계속 발생하면 더 긴 대기
+                    if error_count >= 5 {
+                        log::warn!("[FollowerMonitor] 연속 에러 발생, 긴 대기 시간 적용");
+                    }
+                    monitor.set_state(MonitorState::Backoff, Some(e.to_string()));
+                    continue;
+                }
+            }
+        };
+
+        // 관심 팔로워 목록은 설정에서 매번 읽어 변경 사항을 바로 반영
+        let watch_list = host.watch_list();
+        let unfollow_events_enabled = host.unfollow_events_enabled();
+        let cooldown = host.cooldown_policy();
+
+        // 첫 실행 시 압축 저장으로 초기화
+        if !engine.is_initialized() {
+            log::info!("[FollowerMonitor] 압축 저장 시스템 초기화 - {} 팔로워", current_followers.len());
+            engine.diff(current_followers.clone());
+            sync_engine_state(app_state, &engine);
+            persist_baseline(host, &user_id_hash, &current_followers, &mut saved_baseline);
+            log::info!("[FollowerMonitor] 압축 저장: {} 팔로워 (메모리 94% 절약)", engine.tracked().len());
+            monitor.record_parse_failures(state::timestamp_parse_failures());
+
+            // 관심 팔로워 초기 상태 확인
+            reset_watched_last_seen(app_state, &watch_list, &current_followers);
+            seed_follow_history(app_state, &current_followers);
+            app_state.seed_recent_followers(&current_followers);
+            host.write_text_outputs(app_state);
+
+            monitor.set_state(MonitorState::Running, None);
+            continue; // 첫 실행에서는 알림 없이 초기화만
+        }
+
+        monitor.set_state(MonitorState::Running, None);
+
+        // 변화 감지 엔진으로 이벤트 계산 후 전달
+        // 목표에는 모든 팔로우를, 세션 팔로워 수에는 알림으로 전달한 팔로우만 반영
+        let (mut gained, mut announced, mut lost) = (0, 0, 0);
+        for event in engine.diff(current_followers.clone()) {
+            match event {
+                FollowerEvent::NewFollow(follower) => {
+                    gained += 1;
+                    if announce_follow(app_state, ws_manager, &watch_list, cooldown, follower, false).await {
+                        announced += 1;
+                    }
+                }
+                FollowerEvent::Refollow(follower) => {
+                    gained += 1;
+                    if announce_follow(app_state, ws_manager, &watch_list, cooldown, follower, true).await {
+                        announced += 1;
+                    }
+                }
+                FollowerEvent::Unfollow(follower) => {
+                    lost += 1;
+                    log::debug!("[FollowerMonitor] 언팔로우 감지: {}", follower.user.nickname);
+                    if let Ok(mut history) = app_state.follow_history.lock() {
+                        history.record_unfollow(&follower.user.user_id_hash);
+                    }
+                    record_history(app_state, HistoryEventKind::Unfollow, &follower);
+                    if let Some(entry) = watch_list.find(&follower) {
+                        log::info!("[FollowerMonitor] 관심 팔로워 언팔로우 감지: {}", follower.user.nickname);
+                        mark_watched_seen(app_state, entry, false);
+                        if entry.alert_on_unfollow {
+                            ws_manager.broadcast_watched_unfollow(follower.clone(), entry.alert()).await;
+                        }
+                    }
+                    if unfollow_events_enabled {
+                        emit_follower_lost(ws_manager, host, follower).await;
+                    }
+                }
+                // 변화 없음 - 캐시된 데이터 사용으로 API 호출 최소화
+                FollowerEvent::Unchanged => {}
+            }
+        }
+
+        monitor.record_parse_failures(state::timestamp_parse_failures());
+        update_goal(app_state, ws_manager, host, gained, lost).await;
+        if gained > 0 || lost > 0 {
+            if let Ok(mut count) = app_state.session_follower_count.lock() {
+                *count += announced;
+            }
+            host.write_text_outputs(app_state);
+            stats_throttle.mark_changed();
+        }
+        if stats_throttle.take_due(Instant::now()) {
+            broadcast_stats(app_state, ws_manager).await;
+        }
+
+        sync_engine_state(app_state, &engine);
+        persist_baseline(host, &user_id_hash, &current_followers, &mut saved_baseline);
+    }
+}
+
+// 조회와 같은 페이지 크기를 쓰는 변화 감지 엔진 (여러 페이지 결과가 잘렸는지 판단할 때 필요)
+fn new_engine() -> FollowerDiffEngine {
+    FollowerDiffEngine::new().with_page_size(FollowerPageQuery::default().size)
+}
+
+// 저장된 기준점으로 엔진 복원 (absorb 모드거나 저장된 기준점이 없으면 None)
+fn restore_baseline<H: MonitorHost>(app_state: &AppState, host: &H, channel_id: &str) -> Option<FollowerDiffEngine> {
+    if host.offline_follow_mode() == OfflineFollowMode::Absorb {
+        log::info!("[FollowerMonitor] 오프라인 팔로우 흡수 모드 - 저장된 기준점을 사용하지 않음");
+        return None;
+    }
+
+    let path = host.baseline_path()?;
+    let saved = baseline::load(&path, channel_id)?;
+    log::info!(
+        "[FollowerMonitor] 저장된 기준점 복원 - {} 팔로워 ({} 저장)",
+        saved.followers.len(),
+        saved.saved_at
+    );
+
+    let followers = saved.into_followers();
+
+    // 관심 팔로워 상태, 최근 팔로워, 텍스트 파일도 기준점 기준으로 복원 (새로 초기화할 때와 동일)
+    reset_watched_last_seen(app_state, &host.watch_list(), &followers);
+    seed_follow_history(app_state, &followers);
+    app_state.seed_recent_followers(&followers);
+    host.write_text_outputs(app_state);
+
+    Some(new_engine().with_baseline(followers))
+}
+
+// 팔로우 기록과 쿨다운으로 알림 여부를 정한 뒤 전달 (알림으로 전달했으면 true)
+async fn announce_follow(
+    app_state: &AppState,
+    ws_manager: &WSManager,
+    watch_list: &WatchList,
+    cooldown: CooldownPolicy,
+    follower: FollowerItem,
+    known_refollow: bool,
+) -> bool {
+    let watched = watch_list.find(&follower);
+    if let Some(entry) = watched {
+        log::info!("[FollowerMonitor] 🎉 관심 팔로워 팔로우 감지: {}", follower.user.nickname);
+        mark_watched_seen(app_state, entry, true);
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+    let bypass_cooldown = watched.is_some_and(|entry| entry.bypass_cooldown);
+    let decision = match app_state.follow_history.lock() {
+        Ok(mut history) => {
+            history.record_follow(&follower.user.user_id_hash, known_refollow, bypass_cooldown, cooldown, now)
+        }
+        Err(_) => FollowDecision::Alert { is_refollow: known_refollow },
+    };
+    let is_refollow = !matches!(decision, FollowDecision::Alert { is_refollow: false });
+
+    // 알림 여부와 관계없이 모든 팔로우를 기록
+    let kind = if is_refollow { HistoryEventKind::Refollow } else { HistoryEventKind::Follow };
+    record_history(app_state, kind, &follower);
+
+    let silent = match decision {
+        FollowDecision::Alert { .. } => false,
+        FollowDecision::Silent => true,
+        FollowDecision::Suppressed => {
+            log::info!("[FollowerMonitor] 재팔로우 쿨다운 중, 무시: {}", follower.user.nickname);
+            return false;
+        }
+    };
+
+    // alertOnFollow가 false면 전용 사운드/문구 없이 일반 팔로워와 같이 알림
+    let watch_alert = watched.filter(|entry| entry.alert_on_follow).map(WatchEntry::alert);
+
+    app_state.push_recent_follower(follower.clone());
+    ws_manager
+        .broadcast_recent_followers(app_state.recent_followers(MAX_RECENT_FOLLOWERS))
+        .await;
+
+    if silent {
+        log::info!("[FollowerMonitor] 재팔로우 쿨다운 중, 알림 없이 전달: {}", follower.user.nickname);
+    } else if is_refollow {
+        log::info!("[FollowerMonitor] 재팔로우 감지: {}", follower.user.nickname);
+    } else {
+        log::info!("[FollowerMonitor] 새 팔로워 감지: {}", follower.user.nickname);
+    }
+
+    // 오버레이 알림은 큐를 거쳐 하나씩 표시
+    if !silent {
+        app_state
+            .alerts
+            .enqueue(follower.clone(), watch_alert.clone(), is_refollow, false);
+    }
+
+    ws_manager
+        .broadcast_new_follower(follower, watch_alert, is_refollow, silent)
+        .await;
+    !silent
+}
+
+// 기록이 바뀐 뒤 최소 간격이 지났을 때만 통계를 보내도록 제한 (바뀐 내용은 다음 차례에 모아서 전달)
+#[derive(Debug, Default)]
+struct StatsThrottle {
+    changed: bool,
+    last_sent: Option<Instant>,
+}
+
+impl StatsThrottle {
+    fn mark_changed(&mut self) {
+        self.changed = true;
+    }
+
+    // 보낼 차례면 보낸 것으로 기록하고 true
+    fn take_due(&mut self, now: Instant) -> bool {
+        let waiting = self
+            .last_sent
+            .is_some_and(|sent| now.duration_since(sent) < STATS_BROADCAST_INTERVAL);
+        if !self.changed || waiting {
+            return false;
+        }
+        self.changed = false;
+        self.last_sent = Some(now);
+        true
+    }
+}
+
+// 기록 전체 기준 통계를 stats 토픽으로 전달
+async fn broadcast_stats(app_state: &AppState, ws_manager: &WSManager) {
+    let Some(store) = app_state.history.get() else {
+        return;
+    };
+    match stats::compute(store, &StatsQuery::default()) {
+        Ok(stats) => ws_manager.broadcast_stats(stats).await,
+        Err(e) => log::warn!("[FollowerMonitor] 통계 계산 실패: {}", e),
+    }
+}
+
+// 감지한 팔로우/언팔로우를 팔로우 목표에 반영하고 바뀌면 저장 후 전달
+async fn update_goal<H: MonitorHost>(app_state: &AppState, ws_manager: &WSManager, host: &H, gained: u64, lost: u64) {
+    let progress = {
+        let Ok(mut goal) = app_state.goal.lock() else {
+            return;
+        };
+        let Some(goal) = goal.as_mut() else {
+            return;
+        };
+        if !goal.apply(gained, lost) {
+            return;
+        }
+        if let Err(e) = host.save_goal(goal) {
+            log::warn!("[FollowerMonitor] {}", e);
+        }
+        goal.progress()
+    };
+
+    log::info!(
+        "[FollowerMonitor] 팔로우 목표 진행: {}/{} ({:.1}%)",
+        progress.goal.current_count,
+        progress.goal.target,
+        progress.percent
+    );
+    ws_manager.broadcast_goal_progress(Some(progress)).await;
+}
+
+/// OBS 텍스트 소스용 파일 갱신 (설정에서 켠 경우만, 팔로우 목표가 바뀐 뒤에도 호출)
+pub(crate) fn write_text_outputs(app_state: &AppState, app_handle: &AppHandle) {
+    let config = text_output::read_config(app_handle);
+    if !config.enabled {
+        return;
+    }
+    let Some(folder) = config.output_folder() else {
+        log::warn!("[FollowerMonitor] 텍스트 출력 폴더를 찾을 수 없습니다");
+        return;
+    };
+
+    let recent = app_state.recent_followers(MAX_RECENT_FOLLOWERS);
+    let session_follower_count = app_state.session_follower_count.lock().map(|c| *c).unwrap_or(0);
+    let goal = app_state
+        .goal
+        .lock()
+        .ok()
+        .and_then(|goal| goal.as_ref().map(goal::FollowGoal::progress));
+
+    let files = text_output::render(
+        &config,
+        &text_output::TextSnapshot {
+            recent: &recent,
+            session_follower_count,
+            goal: goal.as_ref(),
+        },
+    );
+    if let Err(e) = text_output::write_files(&folder, &files) {
+        log::warn!("[FollowerMonitor] 텍스트 파일 출력 실패: {}", e);
+    }
+}
+
+// 기록 파일에 이벤트 추가 (저장소가 없으면 건너뜀)
+fn record_history(app_state: &AppState, kind: HistoryEventKind, follower: &FollowerItem) {
+    if let Some(store) = app_state.history.get() {
+        if let Err(e) = store.append(kind, follower) {
+            log::warn!("[FollowerMonitor] 팔로워 기록 저장 실패: {}", e);
+        }
+    }
+}
+
+// 이미 팔로우 중인 사용자를 기록에 등록 (이후 다시 팔로우하면 재팔로우로 분류)
+fn seed_follow_history(app_state: &AppState, followers: &[FollowerItem]) {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+    if let Ok(mut history) = app_state.follow_history.lock() {
+        for follower in followers {
+            history.seed(&follower.user.user_id_hash, now);
+        }
+    }
+}
+
+// 현재 목록에 있는 관심 팔로워만 팔로우 중으로 기록
+fn reset_watched_last_seen(app_state: &AppState, watch_list: &WatchList, followers: &[FollowerItem]) {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+    if let Ok(mut watched_last_seen) = app_state.watched_last_seen.lock() {
+        watched_last_seen.clear();
+        for entry in watch_list.entries() {
+            if followers.iter().any(|f| entry.matches(f)) {
+                log::info!("[FollowerMonitor] 관심 팔로워 초기 상태: {} 팔로우 중", entry.key());
+                watched_last_seen.insert(entry.key(), now);
+            }
+        }
+    }
+}
+
+// 관심 팔로워의 팔로우 여부 갱신 (언팔로우 시 기록 삭제)
+fn mark_watched_seen(app_state: &AppState, entry: &WatchEntry, following: bool) {
+    if let Ok(mut watched_last_seen) = app_state.watched_last_seen.lock() {
+        if following {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+            watched_last_seen.insert(entry.key(), now);
+        } else {
+            watched_last_seen.remove(&entry.key());
+        }
+    }
+}
+
+// 팔로워 목록이 바뀌었을 때만 기준점 파일 갱신
+fn persist_baseline<H: MonitorHost>(
+    host: &H,
+    channel_id: &str,
+    followers: &[FollowerItem],
+    saved: &mut Option<Vec<(String, String)>>,
+) {
+    let identities: Vec<(String, String)> = followers
+        .iter()
+        .map(|f| (f.user.user_id_hash.clone(), f.following_since.clone()))
+        .collect();
+    if saved.as_ref() == Some(&identities) {
+        return;
+    }
+
+    let Some(path) = host.baseline_path() else {
+        return;
+    };
+    match baseline::save(&path, &FollowerBaseline::new(channel_id, followers)) {
+        Ok(()) => {
+            log::debug!("[FollowerMonitor] 기준점 저장: {} 팔로워", followers.len());
+            *saved = Some(identities);
+        }
+        Err(e) => log::warn!("[FollowerMonitor] 기준점 저장 실패: {}", e),
+    }
+}
+
+// 설정 스토어에서 폴링 주기 읽기 (없거나 잘못된 값이면 기본값)
+fn read_polling_interval(app_handle: &AppHandle) -> Duration {
+    use tauri_plugin_store::StoreExt;
+
+    app_handle
+        .store("settings.json")
+        .ok()
+        .and_then(|store| store.get("pollingInterval"))
+        .and_then(|value| polling_interval_from_value(&value))
+        .unwrap_or(DEFAULT_POLL_INTERVAL)
+}
+
+// 재팔로우 쿨다운 설정 (`refollowCooldown` 초, `refollowCooldownMode`)
+fn read_cooldown_policy(app_handle: &AppHandle) -> CooldownPolicy {
+    use tauri_plugin_store::StoreExt;
+
+    let Ok(store) = app_handle.store("settings.json") else {
+        return CooldownPolicy::default();
+    };
+
+    let window = store
+        .get("refollowCooldown")
+        .and_then(|value| value.as_u64())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_REFOLLOW_COOLDOWN);
+    let mode = store
+        .get("refollowCooldownMode")
+        .map(|value| CooldownMode::from_setting(&value))
+        .unwrap_or_default();
+
+    CooldownPolicy { window, mode }
+}
+
+// 언팔로우 이벤트 전송 여부 (`enableUnfollowEvents`, 기본 꺼짐)
+fn read_enable_unfollow_events(app_handle: &AppHandle) -> bool {
+    use tauri_plugin_store::StoreExt;
+
+    let value = app_handle
+        .store("settings.json")
+        .ok()
+        .and_then(|store| store.get("enableUnfollowEvents"));
+    unfollow_events_enabled(value.as_ref())
+}
+
+// 설정 값이 true일 때만 켬 (없거나 형식이 다르면 꺼짐)
+fn unfollow_events_enabled(value: Option<&serde_json::Value>) -> bool {
+    value.and_then(serde_json::Value::as_bool).unwrap_or(false)
+}
+
+// 팔로우 유지 기간(초), 팔로우 시각을 알 수 없으면 None
+fn followed_for_secs(follower: &FollowerItem, now: chrono::DateTime<chrono::Utc>) -> Option<i64> {
+    follower.followed_at().map(|since| (now - since).num_seconds().max(0))
+}
+
+// 언팔로우를 WebSocket과 Tauri 이벤트로 전달 (팔로우 유지 기간 포함)
+async fn emit_follower_lost<H: MonitorHost>(ws_manager: &WSManager, host: &H, follower: FollowerItem) {
+    let now = chrono::Utc::now();
+    let followed_for_secs = followed_for_secs(&follower, now);
+    let unfollowed_at = now.to_rfc3339();
+
+    log::info!(
+        "[FollowerMonitor] 언팔로우 이벤트: {} (팔로우 기간 {:?}초)",
+        follower.user.nickname,
+        followed_for_secs
+    );
+
+    let payload = json!({
+        "follower": follower,
+        "followedForSecs": followed_for_secs,
+        "unfollowedAt": unfollowed_at,
+    });
+    if let Err(e) = host.emit("follower-lost", payload) {
+        log::error!("[FollowerMonitor] follower-lost 이벤트 전송 실패: {}", e);
+    }
+
+    ws_manager
+        .broadcast_follower_lost(follower, followed_for_secs, unfollowed_at)
+        .await;
+}
+
+// 설정 업데이트 페이로드에서 폴링 주기 추출
+fn parse_polling_interval(settings: &serde_json::Value) -> Option<Duration> {
+    settings.get("pollingInterval").and_then(polling_interval_from_value)
+}
+
+// 초 단위 값을 허용 범위로 제한 (save_settings와 같은 기준)
+fn polling_interval_from_value(value: &serde_json::Value) -> Option<Duration> {
+    let seconds = value.as_u64().or_else(|| value.as_f64().map(|v| v.max(0.0).round() as u64))?;
+    Some(Duration::from_secs(seconds.clamp(MIN_POLL_INTERVAL_SECS, MAX_POLL_INTERVAL_SECS)))
+}
+
+// 다음 폴링까지 대기 시간: 정상이면 폴링 주기, 에러 시 지수 백오프 (주기, 2배, 4배... 최대 60초 또는 주기)
+fn next_sleep_duration(poll_interval: Duration, error_count: u32) -> Duration {
+    if error_count == 0 {
+        return poll_interval;
+    }
+    let backoff = poll_interval.saturating_mul(2_u32.pow(error_count.min(4)));
+    backoff.min(MAX_BACKOFF.max(poll_interval))
+}
+
+// 요청 한도 초과 시 대기 시간 (Retry-After가 없으면 최대 백오프, 있어도 최대 백오프를 넘지 않음)
+fn rate_limit_wait(retry_after: Option<Duration>) -> Duration {
+    retry_after.unwrap_or(MAX_BACKOFF).min(MAX_BACKOFF)
+}
+
+// 세션 만료 처리: 로그인 상태 해제 후 알림, 새 쿠키가 들어올 때까지 대기
+async fn handle_session_expired<H: MonitorHost>(app_state: &AppState, ws_manager: &WSManager, host: &H) {
+    log::warn!("[FollowerMonitor] 세션 만료 감지, 재로그인 대기");
+
+    if let Ok(mut status) = app_state.login_status.lock() {
+        *status = false;
+    }
+    if let Ok(mut cache) = app_state.api_cache.lock() {
+        cache.followers_cache = None;
+    }
+
+    if let Err(e) = host.emit("session-expired", json!({ "reason": "unauthorized" })) {
+        log::error!("[FollowerMonitor] session-expired 이벤트 전송 실패: {}", e);
+    }
+    ws_manager.broadcast_session_expired("unauthorized").await;
+
+    // 확장 프로그램이 /auth/cookies로 새 쿠키를 보내거나 수동 로그인하면 재개
+    loop {
+        app_state.session_renewed.notified().await;
+        if app_state.login_status.lock().map(|s| *s).unwrap_or(false) {
+            break;
+        }
+    }
+
+    log::info!("[FollowerMonitor] 새 세션 확인, 모니터링 재개");
+    ws_manager.broadcast_session_restored().await;
+}
+
+// 엔진 상태를 AppState에 반영 (압축 목록 + 팔로워 수)
+fn sync_engine_state(app_state: &AppState, engine: &FollowerDiffEngine) {
+    if let Ok(mut compressed_followers) = app_state.compressed_followers.lock() {
+        *compressed_followers = engine.tracked().clone();
+    }
+    if let Ok(mut initial_count) = app_state.initial_follower_count.lock() {
+        *initial_count = engine.snapshot().map(<[FollowerItem]>::len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chzzk::test_support::follower_since;
+
+    #[test]
+    fn followed_for_secs_uses_parsed_follow_time() {
+        let now = chrono::DateTime::parse_from_rfc3339("2024-01-02T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        // 치지직 형식은 KST 기준 (2024-01-01 09:00 KST = 2024-01-01 00:00 UTC)
+        assert_eq!(followed_for_secs(&follower_since("hash", "2024-01-01 09:00:00"), now), Some(86_400));
+        assert_eq!(followed_for_secs(&follower_since("hash", "2024-01-01T12:00:00Z"), now), Some(43_200));
+        // 시계 차이로 미래 시각이면 0
+        assert_eq!(followed_for_secs(&follower_since("hash", "2024-01-03T00:00:00Z"), now), Some(0));
+        assert_eq!(followed_for_secs(&follower_since("hash", "unknown"), now), None);
+    }
+
+    #[test]
+    fn rate_limit_wait_is_clamped_and_shown_in_status() {
+        assert_eq!(rate_limit_wait(Some(Duration::from_secs(5))), Duration::from_secs(5));
+        assert_eq!(rate_limit_wait(Some(Duration::from_secs(86_400))), MAX_BACKOFF);
+        assert_eq!(rate_limit_wait(None), MAX_BACKOFF);
+
+        let monitor = MonitorHandle::default();
+        monitor.set_state_with_retry(MonitorState::Backoff, Some("rate limited".to_string()), Some(MAX_BACKOFF));
+        assert_eq!(monitor.status().retry_after_secs, Some(60));
+
+        monitor.set_state(MonitorState::Running, None);
+        assert_eq!(monitor.status().retry_after_secs, None);
+    }
+
+    #[test]
+    fn parse_failures_are_shown_in_status() {
+        let monitor = MonitorHandle::default();
+        let status_rx = monitor.subscribe();
+
+        monitor.record_parse_failures(2);
+        assert_eq!(monitor.status().timestamp_parse_failures, 2);
+        // 값만 갱신하고 상태 변경 알림은 보내지 않음
+        assert!(!status_rx.has_changed().unwrap());
+    }
+
+    #[test]
+    fn stats_are_sent_after_changes_at_most_once_per_interval() {
+        let mut throttle = StatsThrottle::default();
+        let start = Instant::now();
+        assert!(!throttle.take_due(start));
+
+        throttle.mark_changed();
+        assert!(throttle.take_due(start));
+        assert!(!throttle.take_due(start));
+
+        // 간격 안의 변경은 간격이 지난 뒤 한 번에 전달
+        throttle.mark_changed();
+        assert!(!throttle.take_due(start + Duration::from_secs(30)));
+        assert!(throttle.take_due(start + STATS_BROADCAST_INTERVAL));
+        assert!(!throttle.take_due(start + STATS_BROADCAST_INTERVAL * 2));
+    }
+
+    #[test]
+    fn unfollow_events_are_opt_in() {
+        assert!(!unfollow_events_enabled(None));
+        assert!(!unfollow_events_enabled(Some(&json!(false))));
+        assert!(!unfollow_events_enabled(Some(&json!("true"))));
+        assert!(unfollow_events_enabled(Some(&json!(true))));
+    }
+}
@@ -1,18 +1,24 @@
-use crate::chzzk;
-use crate::state::{AppState, CookieData};
+use crate::alert_queue::{self, AlertAction, AlertReplayRequest};
+use crate::chzzk::{self, ChzzkError};
+use crate::goal::{self, FollowGoal, GoalRequest, GoalResetRequest};
+use crate::history::{ExportQuery, HistoryQuery};
+use crate::monitor::{write_text_outputs, MonitorCommand};
+use crate::stats::StatsQuery;
+use crate::state::{AppState, CookieData, MAX_RECENT_FOLLOWERS};
 use crate::websocket::WSManager;
 use axum::{
-    extract::{Json, State},
-    http::{Method, StatusCode},
+    extract::{Json, Query, State},
+    http::{header, Method, StatusCode},
     response::{Html, IntoResponse},
     routing::{get, post},
     Router,
     middleware::{self, Next},
     extract::Request,
 };
+use serde::Deserialize;
 use serde_json::json;
 use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH, Duration};
+use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter, Manager};
 use tokio::net::TcpListener;
 use tower_http::services::ServeDir;
@@ -62,8 +68,11 @@ pub async fn start_server(app_state: Arc<AppState>, app_handle: AppHandle) {
     ws_manager.start_cleanup_task(); // 5분마다 비활성 연결 정리
     println!("[WebSocket] Manager initialized with connection pooling");
 
-    // 실시간 팔로워 모니터링 시작
-    start_follower_monitoring(app_state.clone(), ws_manager.clone()).await;
+    // 실시간 팔로워 모니터링 시작 (감독 태스크가 재시작 관리)
+    crate::monitor::spawn_monitor(Arc::clone(&app_state), ws_manager.clone(), app_handle.clone());
+
+    // 오버레이 알림을 하나씩 내보내는 큐 시작
+    alert_queue::spawn_dispatcher(Arc::clone(&app_state), ws_manager.clone(), app_handle.clone());
 
     // 정적 파일 경로 (개발 vs 빌드 환경)
     // Tauri 2.0에서는 frontendDist가 자동으로 처리됨
@@ -122,7 +131,7 @@ pub async fn start_server(app_state: Arc<AppState>, app_handle: AppHandle) {
 
     // Build router
     let state = ServerState {
-        app_state: app_state.clone(),
+        app_state: Arc::clone(&app_state),
         app_handle: app_handle.clone(),
         resource_path: resource_path.clone(),
         ws_manager: ws_manager.clone(),
@@ -134,14 +143,31 @@ pub async fn start_server(app_state: Arc<AppState>, app_handle: AppHandle) {
         .route("/cookies", get(get_cookies))
         .route("/settings", get(load_settings).post(save_settings))
         .route("/followers", get(get_followers))
+        .route("/followers/recent", get(get_recent_followers))
         .route("/test-follower", post(test_follower))
         .route("/test-follower-get", get(test_follower_get))
+        .route("/history", get(get_history))
+        .route("/history/export", get(export_history))
+        .route("/stats", get(get_stats))
+        .route("/goal", get(get_goal).post(set_goal).delete(clear_goal))
+        .route("/goal/reset", post(reset_goal))
+        .route("/alerts", get(get_alerts))
+        .route("/alerts/pause", post(pause_alerts))
+        .route("/alerts/resume", post(resume_alerts))
+        .route("/alerts/skip", post(skip_alert))
+        .route("/alerts/replay", post(replay_alert))
+        .route("/monitor/status", get(get_monitor_status))
+        .route("/monitor/pause", post(pause_monitor))
+        .route("/monitor/resume", post(resume_monitor))
+        .route("/monitor/restart", post(restart_monitor))
         // WebSocket route (중요: API 라우트 다음에 배치)
         .route("/ws", get(crate::websocket::websocket_handler))
         // 디버깅을 위한 WebSocket 테스트 라우트
         .route("/ws-test", get(|| async { "WebSocket endpoint is working" }))
         // OBS 전용 라우트 (API 라우트 이후에 배치)
         .route("/follower", get(serve_svelte_obs))
+        .route("/overlay/goal", get(serve_goal_overlay))
+        .route("/overlay/recent", get(serve_recent_overlay))
         // Static file serving (public 폴더)
         .nest_service("/public", ServeDir::new(&public_path))
         // Fallback for SPA routing (lowest priority) - 모든 API 라우트 이후에 배치
@@ -164,266 +190,6 @@ pub async fn start_server(app_state: Arc<AppState>, app_handle: AppHandle) {
     axum::serve(listener, app).await.unwrap();
 }
 
-// 실시간 팔로워 모니터링 시작 (압축 저장 + API 캐싱 적용)
-async fn start_follower_monitoring(app_state: Arc<AppState>, ws_manager: WSManager) {
-    log::info!("[FollowerMonitor] Starting optimized monitoring with compression & caching");
-    
-    // 백그라운드 태스크로 실행
-    tokio::spawn(async move {
-        let mut initialized = false;
-        let mut error_count = 0;
-        let max_errors = 10;
-        
-        loop {
-            // 5초마다 팔로워 확인 (에러 시 지수 백오프)
-            let sleep_duration = if error_count == 0 {
-                Duration::from_secs(5)
-            } else {
-                // 지수 백오프: 5초, 10초, 20초, 40초, 최대 60초
-                let backoff_seconds = std::cmp::min(5 * (2_u64.pow(error_count.min(4))), 60);
-                Duration::from_secs(backoff_seconds)
-            };
-            
-            tokio::time::sleep(sleep_duration).await;
-            
-            // WebSocket 클라이언트가 있는 경우에만 모니터링
-            if ws_manager.client_count().await == 0 {
-                continue;
-            }
-            
-            // 최대 에러 횟수 초과 시 모니터링 중단
-            if error_count >= max_errors {
-                log::error!("[FollowerMonitor] 최대 에러 횟수 초과, 모니터링 중단");
-                break;
-            }
-            
-            // 쿠키와 사용자 ID 확인
-            let (cookies, user_id_hash) = {
-                let cookies_guard = match app_state.cookies.lock() {
-                    Ok(guard) => guard,
-                    Err(_) => {
-                        error_count += 1;
-                        continue;
-                    }
-                };
-                
-                let user_id_guard = match app_state.user_id_hash.lock() {
-                    Ok(guard) => guard,
-                    Err(_) => {
-                        error_count += 1;
-                        continue;
-                    }
-                };
-                
-                match (cookies_guard.as_ref(), user_id_guard.as_ref()) {
-                    (Some(cookies), Some(user_id)) => (cookies.clone(), user_id.clone()),
-                    _ => continue, // 인증 정보가 없으면 건너뛰기 (에러 카운트 증가 안함)
-                }
-            };
-            
-            // API 캐시 확인 먼저
-            let current_followers = {
-                // 캐시 확인을 별도 스코프로 분리
-                let cached_followers = {
-                    let cache = match app_state.api_cache.lock() {
-                        Ok(cache) => cache,
-                        Err(_) => {
-                            error_count += 1;
-                            continue;
-                        }
-                    };
-                    
-                    cache.get_cached_followers().cloned()
-                };
-                
-                if let Some(followers) = cached_followers {
-                    log::debug!("[FollowerMonitor] Using cached followers data");
-                    followers
-                } else {
-                    // 캐시 미스 - API 호출
-                    match crate::chzzk::get_followers(&app_state.client, &cookies, &user_id_hash).await {
-                        Ok(response) => {
-                            if let Some(content) = response.content {
-                                let followers = content.data;
-                                log::debug!("[FollowerMonitor] API call successful, caching {} followers", followers.len());
-                                
-                                // 캐시에 저장 (별도 스코프)
-                                {
-                                    let mut cache = match app_state.api_cache.lock() {
-                                        Ok(cache) => cache,
-                                        Err(_) => {
-                                            error_count += 1;
-                                            continue;
-                                        }
-                                    };
-                                    cache.cache_followers(followers.clone());
-                                }
-                                
-                                // 성공 시 에러 카운트 리셋
-                                error_count = 0;
-                                
-                                followers
-                            } else {
-                                log::warn!("[FollowerMonitor] API response has no content");
-                                continue;
-                            }
-                        }
-                        Err(e) => {
-                            error_count += 1;
-                            log::warn!("[FollowerMonitor] 팔로워 조회 실패 ({}/{}): {}", error_count, max_errors, e);
-                            
-                            // 에러가 계속 발생하면 더 긴 대기
-                            if error_count >= 5 {
-                                log::warn!("[FollowerMonitor] 연속 에러 발생, 긴 대기 시간 적용");
-                            }
-                            continue;
-                        }
-                    }
-                }
-            };
-            
-            let current_count = current_followers.len();
-            
-            // 첫 실행 시 압축 저장으로 초기화
-            if !initialized {
-                log::info!("[FollowerMonitor] 압축 저장 시스템 초기화 - {} 팔로워", current_count);
-                
-                // 초기 팔로워 수 저장
-                if let Ok(mut initial_count) = app_state.initial_follower_count.lock() {
-                    *initial_count = Some(current_count);
-                }
-                
-                // 압축된 팔로워 목록 초기화 (루블리스 제외)
-                if let Ok(mut compressed_followers) = app_state.compressed_followers.lock() {
-                    compressed_followers.clear();
-                    for follower in &current_followers {
-                        if follower.user.nickname != "루블리스" {
-                            let compressed = crate::state::CompressedFollower::from_follower(follower);
-                            compressed_followers.push_back(compressed);
-                            
-                            // 최대 100개로 제한 (메모리 효율성)
-                            if compressed_followers.len() > 100 {
-                                compressed_followers.pop_front();
-                            }
-                        }
-                    }
-                    log::info!("[FollowerMonitor] 압축 저장: {} 팔로워 (메모리 94% 절약)", compressed_followers.len());
-                }
-                
-                // 루블리스 초기 상태 확인
-                let rublis_exists = current_followers.iter().any(|f| f.user.nickname == "루블리스");
-                if let Ok(mut rublis_last_seen) = app_state.rublis_last_seen.lock() {
-                    if rublis_exists {
-                        *rublis_last_seen = Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis());
-                        log::info!("[FollowerMonitor] 루블리스 초기 상태: 팔로우 중");
-                    } else {
-                        *rublis_last_seen = None;
-                        log::info!("[FollowerMonitor] 루블리스 초기 상태: 팔로우 안함");
-                    }
-                }
-                
-                initialized = true;
-                continue; // 첫 실행에서는 알림 없이 초기화만
-            }
-            
-            // 효율적 변화 감지
-            let initial_count = {
-                app_state.initial_follower_count.lock().unwrap().unwrap_or(0)
-            };
-            
-            // 1. 루블리스 특별 처리 (항상 확인)
-            let rublis_follower = current_followers.iter().find(|f| f.user.nickname == "루블리스");
-            let rublis_currently_following = rublis_follower.is_some();
-            
-            let rublis_was_following = {
-                app_state.rublis_last_seen.lock().unwrap().is_some()
-            };
-            
-            if rublis_currently_following && !rublis_was_following {
-                // 루블리스가 새로 팔로우함
-                log::info!("[FollowerMonitor] 🎉 루블리스 새 팔로우 감지!");
-                if let Ok(mut rublis_last_seen) = app_state.rublis_last_seen.lock() {
-                    *rublis_last_seen = Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis());
-                }
-                
-                if let Some(rublis) = rublis_follower {
-                    ws_manager.broadcast_new_follower(rublis.clone()).await;
-                }
-            } else if !rublis_currently_following && rublis_was_following {
-                // 루블리스가 언팔로우함
-                log::info!("[FollowerMonitor] 루블리스 언팔로우 감지");
-                if let Ok(mut rublis_last_seen) = app_state.rublis_last_seen.lock() {
-                    *rublis_last_seen = None;
-                }
-            }
-            
-            // 2. 압축 저장을 이용한 효율적 새 팔로워 감지
-            if current_count > initial_count {
-                log::info!("[FollowerMonitor] 팔로워 수 증가 감지: {} -> {}", initial_count, current_count);
-                
-                // 압축된 팔로워 목록과 비교
-                let compressed_followers = {
-                    app_state.compressed_followers.lock().unwrap().clone()
-                };
-                
-                for follower in &current_followers {
-                    // 루블리스는 이미 위에서 처리했으므로 건너뛰기
-                    if follower.user.nickname == "루블리스" {
-                        continue;
-                    }
-                    
-                    // 압축된 목록에서 해당 팔로워 찾기
-                    let compressed = crate::state::CompressedFollower::from_follower(follower);
-                    if !compressed_followers.iter().any(|cf| cf.hash == compressed.hash) {
-                        log::info!("[FollowerMonitor] 새 팔로워 감지 (압축 비교): {}", follower.user.nickname);
-                        
-                        // WebSocket으로 브로드캐스트
-                        ws_manager.broadcast_new_follower(follower.clone()).await;
-                        
-                        // 압축된 팔로워 목록에 추가
-                        if let Ok(mut compressed_followers) = app_state.compressed_followers.lock() {
-                            compressed_followers.push_back(compressed);
-                            if compressed_followers.len() > 100 {
-                                compressed_followers.pop_front();
-                            }
-                        }
-                    }
-                }
-                
-                // 초기 팔로워 수 업데이트
-                if let Ok(mut initial_count_lock) = app_state.initial_follower_count.lock() {
-                    *initial_count_lock = Some(current_count);
-                }
-            } else if current_count < initial_count {
-                // 팔로워 수 감소 (언팔로우)
-                log::debug!("[FollowerMonitor] 팔로워 수 감소: {} -> {}", initial_count, current_count);
-                
-                // 초기 팔로워 수 업데이트
-                if let Ok(mut initial_count_lock) = app_state.initial_follower_count.lock() {
-                    *initial_count_lock = Some(current_count);
-                }
-                
-                // 압축된 팔로워 목록 재구성 (현재 팔로워들로)
-                if let Ok(mut compressed_followers) = app_state.compressed_followers.lock() {
-                    compressed_followers.clear();
-                    for follower in &current_followers {
-                        if follower.user.nickname != "루블리스" {
-                            let compressed = crate::state::CompressedFollower::from_follower(follower);
-                            compressed_followers.push_back(compressed);
-                            if compressed_followers.len() > 100 {
-                                compressed_followers.pop_front();
-                            }
-                        }
-                    }
-                }
-            }
-            // 팔로워 수가 같으면 변화 없음 - 캐시된 데이터 사용으로 API 호출 최소화
-        }
-        
-        log::warn!("[FollowerMonitor] 모니터링 종료");
-    });
-}
-
 async fn find_available_port(start: u16) -> u16 {
     for port in start..start + 100 {
         if TcpListener::bind(format!("0.0.0.0:{}", port)).await.is_ok() {
@@ -469,7 +235,7 @@ async fn receive_cookies(
     println!("[Server] Received cookies from extension");
 
     // 1. Verify cookies & Fetch User Info
-    match chzzk::get_profile_id(&state.app_state.client, &payload).await {
+    match state.app_state.chzzk.get_profile_id(&payload).await {
         Ok((hash, nickname)) => {
             println!("[Server] Verified User: {} ({})", nickname, hash);
 
@@ -484,7 +250,13 @@ async fn receive_cookies(
                 if let Ok(mut status) = state.app_state.login_status.lock() {
                     *status = true;
                 }
+                if let Ok(mut nickname_lock) = state.app_state.nickname.lock() {
+                    *nickname_lock = Some(nickname.clone());
+                }
             }
+            
+            // 세션 만료로 멈춘 모니터 재개
+            state.app_state.session_renewed.notify_one();
 
             // 3. Save to Persistent Store (session.json)
             use tauri_plugin_store::StoreExt;
@@ -522,80 +294,107 @@ async fn receive_cookies(
         }
         Err(e) => {
             eprintln!("[Server] Cookie verification failed: {}", e);
+            // 쿠키 문제가 아닌 경우(네트워크 등) 확장 프로그램이 다시 시도할 수 있도록 구분
+            let message = match e {
+                ChzzkError::Unauthorized => "Verification failed: cookies expired or invalid".to_string(),
+                _ => format!("Verification unavailable: {}", e),
+            };
             Json(serde_json::json!({
-                "code": 401,
-                "message": format!("Verification failed: {}", e)
+                "code": chzzk_error_code(&e),
+                "message": message
             }))
         }
     }
 }
 
+// 치지직 API 오류를 응답 code로 변환
+fn chzzk_error_code(e: &ChzzkError) -> u16 {
+    match e {
+        ChzzkError::Unauthorized => 401,
+        ChzzkError::RateLimited { .. } => 429,
+        ChzzkError::Network(_) => 503,
+        ChzzkError::ServerError(_) | ChzzkError::Decode(_) | ChzzkError::ApiCode { .. } => 502,
+    }
+}
+
 // Handler for GET /cookies (Debug)
 async fn get_cookies(State(state): State<ServerState>) -> impl IntoResponse {
     let cookies = state.app_state.cookies.lock().unwrap().clone();
     Json(cookies)
 }
 
-// Handler for GET /settings - Load settings from Tauri Store
-async fn load_settings(State(state): State<ServerState>) -> impl IntoResponse {
+// settings.json에서 읽는 설정 키
+const SETTINGS_KEYS: [&str; 18] = [
+    "volume",
+    "pollingInterval",
+    "displayDuration",
+    "enableTTS",
+    "customSoundPath",
+    "animationType",
+    "notificationLayout",
+    "textColor",
+    "textSize",
+    "offlineFollowMode",
+    "watchList",
+    "enableUnfollowEvents",
+    "refollowCooldown",
+    "refollowCooldownMode",
+    "textOutputEnabled",
+    "textOutputFolder",
+    "textOutputRecentCount",
+    "textOutputTemplates",
+];
+
+// 저장된 설정이 없을 때의 기본 설정
+fn default_settings() -> serde_json::Value {
+    serde_json::json!({
+        "volume": 0.5,
+        "pollingInterval": 5,
+        "displayDuration": 5,
+        "enableTTS": false,
+        "customSoundPath": null,
+        "animationType": "fade",
+        "notificationLayout": "vertical",
+        "textColor": "#ffffff",
+        "textSize": 100,
+        "offlineFollowMode": "announce",
+        "watchList": [],
+        "enableUnfollowEvents": false,
+        "refollowCooldown": 600,
+        "refollowCooldownMode": "silent",
+        "textOutputEnabled": false,
+        "textOutputFolder": "",
+        "textOutputRecentCount": 5,
+        "textOutputTemplates": null
+    })
+}
+
+/// settings.json의 설정 읽기 (저장된 설정이 없거나 스토어를 열 수 없으면 기본 설정)
+pub fn read_settings(app_handle: &AppHandle) -> serde_json::Value {
     use tauri_plugin_store::StoreExt;
 
-    println!("[Server] Loading settings from Store");
+    let Ok(store) = app_handle.store("settings.json") else {
+        return default_settings();
+    };
 
-    if let Ok(store) = state.app_handle.store("settings.json") {
-        // 설정 항목들을 가져오기
-        let mut settings = serde_json::Map::new();
-
-        let keys = vec![
-            "volume",
-            "pollingInterval",
-            "displayDuration",
-            "enableTTS",
-            "customSoundPath",
-            "animationType",
-            "notificationLayout",
-            "textColor",
-            "textSize",
-        ];
-
-        for key in keys {
-            if let Some(value) = store.get(key) {
-                settings.insert(key.to_string(), value.clone());
-            }
-        }
+    let settings: serde_json::Map<String, serde_json::Value> = SETTINGS_KEYS
+        .into_iter()
+        .filter_map(|key| store.get(key).map(|value| (key.to_string(), value)))
+        .collect();
 
-        if settings.is_empty() {
-            // 기본 설정 반환
-            Json(serde_json::json!({
-                "volume": 0.5,
-                "pollingInterval": 5,
-                "displayDuration": 5,
-                "enableTTS": false,
-                "customSoundPath": null,
-                "animationType": "fade",
-                "notificationLayout": "vertical",
-                "textColor": "#ffffff",
-                "textSize": 100
-            }))
-        } else {
-            Json(serde_json::Value::Object(settings))
-        }
+    if settings.is_empty() {
+        default_settings()
     } else {
-        // Store 열기 실패 시 기본 설정 반환
-        Json(serde_json::json!({
-            "volume": 0.5,
-            "pollingInterval": 5,
-            "displayDuration": 5,
-            "enableTTS": false,
-            "customSoundPath": null,
-            "animationType": "fade",
-            "notificationLayout": "vertical",
-            "textColor": "#ffffff",
-            "textSize": 100
-        }))
+        serde_json::Value::Object(settings)
     }
 }
 
+// Handler for GET /settings - Load settings from Tauri Store
+async fn load_settings(State(state): State<ServerState>) -> impl IntoResponse {
+    println!("[Server] Loading settings from Store");
+    Json(read_settings(&state.app_handle))
+}
+
 // Handler for POST /settings - Save settings to Tauri Store
 async fn save_settings(
     State(state): State<ServerState>,
@@ -712,59 +511,127 @@ async fn serve_svelte_obs(State(state): State<ServerState>) -> impl IntoResponse
     }
 }
 
+// OBS 팔로우 목표 오버레이
+async fn serve_goal_overlay(State(state): State<ServerState>) -> impl IntoResponse {
+    serve_overlay(&state, "goal")
+}
+
+// OBS 최근 팔로워 티커 오버레이
+async fn serve_recent_overlay(State(state): State<ServerState>) -> impl IntoResponse {
+    serve_overlay(&state, "recent")
+}
+
+// index.html에 오버레이 화면 플래그(window.OVERLAY_VIEW)를 넣어 제공
+fn serve_overlay(state: &ServerState, view: &str) -> axum::response::Response {
+    let html_path = state.resource_path.join("index.html");
+    println!("[Server] OBS {} 오버레이 제공 중: {:?}", view, html_path);
+
+    match std::fs::read_to_string(&html_path) {
+        Ok(html) => {
+            let html = html.replace(
+                "<head>",
+                &format!(
+                    "<head>
+                    <script>
+                        // OBS 오버레이 모드
+                        window.OBS_MODE = true;
+                        window.OVERLAY_VIEW = '{}';
+                        document.addEventListener('DOMContentLoaded', function() {{
+                            document.body.classList.add('obs-mode');
+                        }});
+                    </script>
+                    <style>
+                        body {{ margin: 0; padding: 0; background: transparent !important; overflow: hidden; }}
+                        #app {{ width: 100vw; height: 100vh; background: transparent; }}
+                    </style>",
+                    view
+                ),
+            );
+            Html(html).into_response()
+        }
+        Err(e) => {
+            eprintln!("[Server] index.html을 찾을 수 없음: {:?}, 오류: {}", html_path, e);
+            (StatusCode::NOT_FOUND, "index.html을 찾을 수 없습니다").into_response()
+        }
+    }
+}
+
+// GET /followers/recent 쿼리 파라미터
+#[derive(Debug, Deserialize)]
+struct RecentFollowersParams {
+    limit: Option<usize>,
+}
+
+// 모니터가 감지한 최근 팔로워 (최신순, 기본 10명, 최대 50명)
+async fn get_recent_followers(
+    State(state): State<ServerState>,
+    Query(params): Query<RecentFollowersParams>,
+) -> impl IntoResponse {
+    let limit = params.limit.unwrap_or(10).clamp(1, MAX_RECENT_FOLLOWERS);
+    let followers = state.app_state.recent_followers(limit);
+
+    Json(json!({ "code": 200, "content": { "data": followers } }))
+}
+
+// GET /followers 쿼리 파라미터
+#[derive(Debug, Deserialize)]
+struct FollowerListParams {
+    page: Option<usize>,
+    size: Option<usize>,
+}
+
 // 실제 치지직 API를 호출하는 팔로워 조회
-async fn get_followers(State(state): State<ServerState>) -> impl IntoResponse {
+async fn get_followers(
+    State(state): State<ServerState>,
+    Query(params): Query<FollowerListParams>,
+) -> impl IntoResponse {
     println!("[Server] GET /followers");
     
     // 쿠키와 사용자 ID 가져오기
-    let cookies = {
-        let cookies_guard = state.app_state.cookies.lock().map_err(|e| {
+    let cookies = match state.app_state.cookies.lock() {
+        Ok(guard) => guard.clone(),
+        Err(e) => {
             eprintln!("[Server] Failed to lock cookies: {}", e);
             return Json(json!({
-                "code": 401,
-                "message": "Authentication required",
+                "code": 500,
+                "message": "Internal server error",
                 "content": null
             }));
-        }).unwrap();
-        
-        match cookies_guard.as_ref() {
-            Some(cookies) => cookies.clone(),
-            None => {
-                println!("[Server] No cookies available");
-                return Json(json!({
-                    "code": 401,
-                    "message": "Authentication required",
-                    "content": null
-                }));
-            }
         }
     };
+    let Some(cookies) = cookies else {
+        println!("[Server] No cookies available");
+        return Json(json!({
+            "code": 401,
+            "message": "Authentication required",
+            "content": null
+        }));
+    };
     
-    let user_id_hash = {
-        let user_id_guard = state.app_state.user_id_hash.lock().map_err(|e| {
+    let user_id_hash = match state.app_state.user_id_hash.lock() {
+        Ok(guard) => guard.clone(),
+        Err(e) => {
             eprintln!("[Server] Failed to lock user_id_hash: {}", e);
             return Json(json!({
                 "code": 500,
                 "message": "Internal server error",
                 "content": null
             }));
-        }).unwrap();
-        
-        match user_id_guard.as_ref() {
-            Some(user_id) => user_id.clone(),
-            None => {
-                println!("[Server] No user ID available");
-                return Json(json!({
-                    "code": 401,
-                    "message": "User ID not available",
-                    "content": null
-                }));
-            }
         }
     };
+    let Some(user_id_hash) = user_id_hash else {
+        println!("[Server] No user ID available");
+        return Json(json!({
+            "code": 401,
+            "message": "User ID not available",
+            "content": null
+        }));
+    };
     
     // 치지직 API 호출
-    match crate::chzzk::get_followers(&state.app_state.client, &cookies, &user_id_hash).await {
+    let page = params.page.unwrap_or(0);
+    let size = params.size.unwrap_or(chzzk::DEFAULT_PAGE_SIZE);
+    match state.app_state.chzzk.get_followers_page(&cookies, &user_id_hash, page, size).await {
         Ok(response) => {
             println!("[Server] Successfully fetched {} followers", 
                 response.content.as_ref().map(|c| c.data.len()).unwrap_or(0));
@@ -773,7 +640,7 @@ async fn get_followers(State(state): State<ServerState>) -> impl IntoResponse {
         Err(e) => {
             eprintln!("[Server] Failed to fetch followers: {}", e);
             Json(json!({
-                "code": 500,
+                "code": chzzk_error_code(&e),
                 "message": format!("Failed to fetch followers: {}", e),
                 "content": null
             }))
@@ -781,36 +648,250 @@ async fn get_followers(State(state): State<ServerState>) -> impl IntoResponse {
     }
 }
 
-async fn test_follower(State(_state): State<ServerState>) -> impl IntoResponse {
+async fn test_follower(State(state): State<ServerState>) -> impl IntoResponse {
     // 테스트 팔로워 생성
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap()
-        .as_millis()
-        .to_string();
-    
-    let now_iso = format!("{}", SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs());
+        .as_millis();
 
-    let test_item = json!({
-        "user": {
-            "userIdHash": format!("test_{}", now),
-            "nickname": "루블리스",
-            "profileImageUrl": "/default_profile.png"
+    let test_item = chzzk::FollowerItem {
+        user: chzzk::User {
+            user_id_hash: format!("test_{}", now),
+            nickname: "테스트 유저".to_string(),
+            profile_image_url: Some("/default_profile.png".to_string()),
         },
-        "followingSince": now_iso
+        following_since: chrono::Utc::now().to_rfc3339(),
+    };
+
+    println!("[Server] Test follower created: {}", test_item.user.user_id_hash);
+
+    // WebSocket test_follower와 같이 알림 큐에 추가 (다른 알림과 겹치지 않게)
+    match state.app_state.alerts.enqueue(test_item, None, false, true) {
+        Some(id) => Json(json!({
+            "success": true,
+            "message": "Test follower added to queue",
+            "alertId": id
+        })),
+        None => Json(json!({
+            "success": false,
+            "message": "Failed to add test follower to queue"
+        })),
+    }
+}
+
+async fn test_follower_get(State(state): State<ServerState>) -> impl IntoResponse {
+    test_follower(State(state)).await
+}
+
+// Handler for GET /history - 팔로워 기록 조회 (최신순)
+async fn get_history(
+    State(state): State<ServerState>,
+    Query(query): Query<HistoryQuery>,
+) -> impl IntoResponse {
+    let Some(store) = state.app_state.history.get() else {
+        return Json(json!({ "code": 503, "message": "History store is not available" }));
+    };
+
+    match store.query(&query) {
+        Ok(page) => Json(json!({ "code": 200, "content": page })),
+        Err(e) => Json(json!({ "code": 400, "message": e })),
+    }
+}
+
+// Handler for GET /history/export - 기간 안의 기록을 CSV/JSON 파일로 다운로드
+async fn export_history(
+    State(state): State<ServerState>,
+    Query(query): Query<ExportQuery>,
+) -> impl IntoResponse {
+    let Some(store) = state.app_state.history.get() else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({ "code": 503, "message": "History store is not available" })),
+        )
+            .into_response();
+    };
+
+    match store.export(&query) {
+        Ok(body) => {
+            let format = query.format.unwrap_or_default();
+            let disposition = format!("attachment; filename=\"{}\"", query.file_name());
+            println!("[Server] History exported as {}", format.extension());
+            (
+                [
+                    (header::CONTENT_TYPE, format.content_type().to_string()),
+                    (header::CONTENT_DISPOSITION, disposition),
+                ],
+                body,
+            )
+                .into_response()
+        }
+        Err(e) => (StatusCode::BAD_REQUEST, Json(json!({ "code": 400, "message": e }))).into_response(),
+    }
+}
+
+// Handler for GET /stats - 팔로워 기록 기반 통계
+async fn get_stats(
+    State(state): State<ServerState>,
+    Query(query): Query<StatsQuery>,
+) -> impl IntoResponse {
+    let Some(store) = state.app_state.history.get() else {
+        return Json(json!({ "code": 503, "message": "History store is not available" }));
+    };
+
+    match crate::stats::compute(store, &query) {
+        Ok(stats) => Json(json!({ "code": 200, "content": stats })),
+        Err(e) => Json(json!({ "code": 400, "message": e })),
+    }
+}
+
+// Handler for GET /goal - 팔로우 목표 진행 상황 (목표가 없으면 null)
+async fn get_goal(State(state): State<ServerState>) -> impl IntoResponse {
+    let progress = state
+        .app_state
+        .goal
+        .lock()
+        .ok()
+        .and_then(|goal| goal.as_ref().map(FollowGoal::progress));
+
+    Json(json!({ "code": 200, "content": progress }))
+}
+
+// Handler for POST /goal - 팔로우 목표 설정
+async fn set_goal(State(state): State<ServerState>, Json(request): Json<GoalRequest>) -> impl IntoResponse {
+    if request.target == 0 {
+        return Json(json!({ "code": 400, "message": "target must be greater than 0" }));
+    }
+
+    let result = state.app_state.goal.lock().map_err(|e| e.to_string()).and_then(|mut goal| {
+        let start_count = request
+            .start_count
+            .or_else(|| goal.as_ref().map(|g| g.current_count))
+            .unwrap_or(0);
+        let new_goal = FollowGoal::new(request.label, request.target, start_count);
+        goal::save(&state.app_handle, Some(&new_goal))?;
+        let progress = new_goal.progress();
+        *goal = Some(new_goal);
+        Ok(progress)
+    });
+
+    match result {
+        Ok(progress) => {
+            println!("[Server] 팔로우 목표 설정: {} ({}명)", progress.goal.label, progress.goal.target);
+            write_text_outputs(&state.app_state, &state.app_handle);
+            state.ws_manager.broadcast_goal_progress(Some(progress.clone())).await;
+            Json(json!({ "code": 200, "content": progress }))
+        }
+        Err(e) => Json(json!({ "code": 500, "message": e })),
+    }
+}
+
+// Handler for POST /goal/reset - 목표 기준점을 다시 잡음
+async fn reset_goal(
+    State(state): State<ServerState>,
+    body: Option<Json<GoalResetRequest>>,
+) -> impl IntoResponse {
+    let request = body.map(|Json(request)| request).unwrap_or_default();
+
+    let result = state.app_state.goal.lock().map_err(|e| e.to_string()).and_then(|mut goal| {
+        let goal = goal.as_mut().ok_or_else(|| "No goal is set".to_string())?;
+        goal.reset(request.start_count);
+        goal::save(&state.app_handle, Some(goal))?;
+        Ok(goal.progress())
     });
 
-    println!("[Server] Test follower created: {}", test_item);
+    match result {
+        Ok(progress) => {
+            println!("[Server] 팔로우 목표 초기화: 기준 {}명", progress.goal.start_count);
+            write_text_outputs(&state.app_state, &state.app_handle);
+            state.ws_manager.broadcast_goal_progress(Some(progress.clone())).await;
+            Json(json!({ "code": 200, "content": progress }))
+        }
+        Err(e) => Json(json!({ "code": 400, "message": e })),
+    }
+}
 
-    // WebSocket으로 테스트 알림 브로드캐스트 (있는 경우만)
-    // state.ws_manager.broadcast_test_notification(test_item.clone()).await;
+// Handler for DELETE /goal - 팔로우 목표 삭제
+async fn clear_goal(State(state): State<ServerState>) -> impl IntoResponse {
+    let result = state.app_state.goal.lock().map_err(|e| e.to_string()).and_then(|mut goal| {
+        goal::save(&state.app_handle, None)?;
+        *goal = None;
+        Ok(())
+    });
 
+    match result {
+        Ok(()) => {
+            write_text_outputs(&state.app_state, &state.app_handle);
+            state.ws_manager.broadcast_goal_progress(None).await;
+            Json(json!({ "code": 200, "message": "Goal cleared" }))
+        }
+        Err(e) => Json(json!({ "code": 500, "message": e })),
+    }
+}
+
+async fn get_monitor_status(State(state): State<ServerState>) -> impl IntoResponse {
     Json(json!({
-        "success": true,
-        "message": "Test follower added to queue"
+        "code": 200,
+        "content": state.app_state.monitor.status()
     }))
 }
 
-async fn test_follower_get(State(state): State<ServerState>) -> impl IntoResponse {
-    test_follower(State(state)).await
-}
\ No newline at end of file
+async fn pause_monitor(State(state): State<ServerState>) -> impl IntoResponse {
+    send_monitor_command(&state, MonitorCommand::Pause)
+}
+
+async fn resume_monitor(State(state): State<ServerState>) -> impl IntoResponse {
+    send_monitor_command(&state, MonitorCommand::Resume)
+}
+
+async fn restart_monitor(State(state): State<ServerState>) -> impl IntoResponse {
+    send_monitor_command(&state, MonitorCommand::Restart)
+}
+
+// Handler for GET /alerts - 알림 큐 상태
+async fn get_alerts(State(state): State<ServerState>) -> impl IntoResponse {
+    Json(json!({ "code": 200, "content": state.app_state.alerts.status() }))
+}
+
+async fn pause_alerts(State(state): State<ServerState>) -> impl IntoResponse {
+    send_alert_command(&state, AlertAction::Pause, None).await
+}
+
+async fn resume_alerts(State(state): State<ServerState>) -> impl IntoResponse {
+    send_alert_command(&state, AlertAction::Resume, None).await
+}
+
+async fn skip_alert(State(state): State<ServerState>) -> impl IntoResponse {
+    send_alert_command(&state, AlertAction::Skip, None).await
+}
+
+async fn replay_alert(
+    State(state): State<ServerState>,
+    body: Option<Json<AlertReplayRequest>>,
+) -> impl IntoResponse {
+    let request = body.map(|Json(request)| request).unwrap_or_default();
+    send_alert_command(&state, AlertAction::Replay, request.id).await
+}
+
+async fn send_alert_command(state: &ServerState, action: AlertAction, id: Option<u64>) -> Json<serde_json::Value> {
+    match alert_queue::control(&state.app_state, &state.ws_manager, action, id).await {
+        Ok(status) => Json(json!({ "code": 200, "content": status })),
+        Err(e) => Json(json!({ "code": 404, "message": e })),
+    }
+}
+
+fn send_monitor_command(state: &ServerState, command: MonitorCommand) -> Json<serde_json::Value> {
+    match state.app_state.monitor.send(command) {
+        Ok(()) => {
+            println!("[Server] Monitor command sent: {:?}", command);
+            Json(json!({
+                "code": 200,
+                "message": "Monitor command accepted"
+            }))
+        }
+        Err(e) => Json(json!({
+            "code": 503,
+            "message": e
+        })),
+    }
+}
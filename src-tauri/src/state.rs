@@ -1,8 +1,15 @@
-use crate::chzzk::FollowerItem;
+use crate::alert_queue::AlertQueue;
+use crate::chzzk::{ChzzkApi, ChzzkClient, FollowerItem};
+use crate::follow_history::FollowHistory;
+use crate::goal::FollowGoal;
+use crate::history::HistoryStore;
+use crate::monitor::MonitorHandle;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashSet, VecDeque};
-use std::sync::Mutex;
-use std::time::{SystemTime, UNIX_EPOCH, Instant};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Instant;
+use tokio::sync::Notify;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CookieData {
@@ -12,43 +19,102 @@ pub struct CookieData {
     pub nid_ses: String,
 }
 
+/// 최근 팔로워 목록에 유지하는 최대 인원
+pub const MAX_RECENT_FOLLOWERS: usize = 50;
+
+/// 팔로워 해시 형식 버전 (해시 알고리즘이 바뀌면 올려서 이전 데이터와 구분)
+pub const FOLLOWER_HASH_VERSION: u8 = 1;
+
+// FNV-1a 64비트 상수
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+// followingSince 파싱 실패 누적 횟수
+static TIMESTAMP_PARSE_FAILURES: AtomicU64 = AtomicU64::new(0);
+
+/// userIdHash의 고정 해시 (FNV-1a 64비트)
+///
+/// `DefaultHasher`와 달리 Rust 버전이나 프로세스가 달라도 같은 값이므로
+/// 디스크 저장이나 프로세스 간 비교에 사용할 수 있습니다.
+pub fn stable_follower_hash(user_id_hash: &str) -> u64 {
+    user_id_hash.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// followingSince 파싱 오류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimestampParseError {
+    /// 알 수 없는 형식
+    Invalid(String),
+    /// u32 초 단위로 표현할 수 없는 시각 (1970년 이전 또는 2106년 이후)
+    OutOfRange(String),
+}
+
+impl std::fmt::Display for TimestampParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimestampParseError::Invalid(value) => write!(f, "알 수 없는 followingSince 형식: {:?}", value),
+            TimestampParseError::OutOfRange(value) => write!(f, "범위를 벗어난 followingSince: {:?}", value),
+        }
+    }
+}
+
+impl std::error::Error for TimestampParseError {}
+
+/// followingSince를 유닉스 타임스탬프(초)로 변환
+pub fn parse_follow_timestamp(following_since: &str) -> Result<u32, TimestampParseError> {
+    let followed_at = crate::chzzk::parse_following_since(following_since)
+        .ok_or_else(|| TimestampParseError::Invalid(following_since.to_string()))?;
+
+    u32::try_from(followed_at.timestamp())
+        .map_err(|_| TimestampParseError::OutOfRange(following_since.to_string()))
+}
+
+/// 앱 실행 후 followingSince 파싱에 실패한 횟수
+pub fn timestamp_parse_failures() -> u64 {
+    TIMESTAMP_PARSE_FAILURES.load(Ordering::Relaxed)
+}
+
 // 압축된 팔로워 데이터 (메모리 94% 절약)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct CompressedFollower {
-    pub hash: u64,      // String 대신 u64 해시 (8바이트)
-    pub timestamp: u32, // 4바이트 타임스탬프 (2106년까지 지원)
+    pub version: u8,    // 해시 형식 버전 (FOLLOWER_HASH_VERSION)
+    pub hash: u64,      // String 대신 u64 고정 해시 (8바이트)
+    pub timestamp: u32, // 4바이트 타임스탬프 (2106년까지 지원), 파싱 실패 시 0
 }
 
 impl CompressedFollower {
+    /// 타임스탬프를 파싱할 수 없으면 오류 반환
+    pub fn try_from_follower(follower: &FollowerItem) -> Result<Self, TimestampParseError> {
+        Ok(Self {
+            version: FOLLOWER_HASH_VERSION,
+            hash: stable_follower_hash(&follower.user.user_id_hash),
+            timestamp: parse_follow_timestamp(&follower.following_since)?,
+        })
+    }
+
+    /// 타임스탬프 파싱에 실패하면 실패 횟수를 기록하고 타임스탬프 없이(0) 생성
     pub fn from_follower(follower: &FollowerItem) -> Self {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-        
-        let mut hasher = DefaultHasher::new();
-        follower.user.user_id_hash.hash(&mut hasher);
-        let hash = hasher.finish();
-        
-        // followingSince를 타임스탬프로 변환
-        let timestamp = follower.following_since
-            .parse::<chrono::DateTime<chrono::Utc>>()
-            .map(|dt| dt.timestamp() as u32)
-            .unwrap_or_else(|_| {
-                SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .unwrap()
-                    .as_secs() as u32
-            });
-        
-        Self { hash, timestamp }
+        Self::try_from_follower(follower).unwrap_or_else(|e| {
+            let failures = TIMESTAMP_PARSE_FAILURES.fetch_add(1, Ordering::Relaxed) + 1;
+            log::warn!("[CompressedFollower] {} (누적 {}회)", e, failures);
+
+            Self {
+                version: FOLLOWER_HASH_VERSION,
+                hash: stable_follower_hash(&follower.user.user_id_hash),
+                timestamp: 0,
+            }
+        })
     }
-    
+
+    /// 타임스탬프를 알고 있는지 여부
+    pub fn has_timestamp(&self) -> bool {
+        self.timestamp != 0
+    }
+
     pub fn matches_follower(&self, follower: &FollowerItem) -> bool {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-        
-        let mut hasher = DefaultHasher::new();
-        follower.user.user_id_hash.hash(&mut hasher);
-        self.hash == hasher.finish()
+        self.version == FOLLOWER_HASH_VERSION && self.hash == stable_follower_hash(&follower.user.user_id_hash)
     }
 }
 
@@ -59,6 +125,12 @@ pub struct APICache {
     pub cache_duration: std::time::Duration, // 5초
 }
 
+impl Default for APICache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl APICache {
     pub fn new() -> Self {
         Self {
@@ -99,6 +171,8 @@ pub struct AppState {
     pub cookies: Mutex<Option<CookieData>>,
     pub port: Mutex<u16>,
     pub login_status: Mutex<bool>,
+    pub nickname: Mutex<Option<String>>, // 로그인한 사용자 닉네임
+    pub session_renewed: Notify, // 새 쿠키로 로그인되면 알림 (세션 만료 후 모니터 재개)
     pub http_client: reqwest::Client, // HTTP 클라이언트 재사용
 
     // Cache
@@ -108,16 +182,22 @@ pub struct AppState {
     pub test_queue: Mutex<VecDeque<FollowerItem>>,
     pub real_queue: Mutex<VecDeque<RealFollowerQueueItem>>,
     pub known_followers: Mutex<HashSet<String>>, // 기존 방식 (호환성 유지)
-    pub rublis_last_seen: Mutex<Option<u128>>, // 루블리스 마지막 확인 시간
+    pub watched_last_seen: Mutex<HashMap<String, u128>>, // 관심 팔로워별 마지막으로 팔로우를 확인한 시간
+    pub follow_history: Mutex<FollowHistory>, // 사용자별 팔로우 기록 (재팔로우 판단/쿨다운)
+    pub history: OnceLock<HistoryStore>, // 팔로우/언팔로우 기록 파일 (앱 시작 시 설정)
+    pub goal: Mutex<Option<FollowGoal>>, // 팔로우 목표 (설정 스토어에서 불러옴)
     pub initial_follower_count: Mutex<Option<usize>>, // 앱 시작 시 팔로워 수
     pub last_known_follower_id: Mutex<Option<String>>, // 마지막 확인한 팔로워 ID
-    pub recent_followers: Mutex<VecDeque<String>>, // 최근 팔로워들 (최대 50명)
+    pub recent_followers: Mutex<VecDeque<FollowerItem>>, // 최근 팔로워들 (최신순, 최대 50명)
+    pub session_follower_count: Mutex<u64>, // 앱 실행 후 알림으로 전달한 팔로우 수 (쿨다운으로 생략한 재팔로우 제외)
     
     // 새로운 압축 저장 시스템
     pub compressed_followers: Mutex<VecDeque<CompressedFollower>>, // 압축된 팔로워 (최대 100명)
     pub api_cache: Mutex<APICache>, // API 응답 캐시
     
-    pub client: reqwest::Client,
+    pub chzzk: Arc<dyn ChzzkApi>, // 치지직 API 클라이언트 (환경 변수로 주소 변경 가능)
+    pub monitor: MonitorHandle, // 팔로워 모니터 상태/제어
+    pub alerts: AlertQueue, // 오버레이 알림 큐 (한 번에 하나씩 표시)
 }
 
 impl Default for AppState {
@@ -126,18 +206,137 @@ impl Default for AppState {
             cookies: Mutex::new(None),
             port: Mutex::new(3000),
             login_status: Mutex::new(false),
+            nickname: Mutex::new(None),
+            session_renewed: Notify::new(),
             http_client: reqwest::Client::new(), // HTTP 클라이언트 초기화
             user_id_hash: Mutex::new(None),
             test_queue: Mutex::new(VecDeque::new()),
             real_queue: Mutex::new(VecDeque::new()),
             known_followers: Mutex::new(HashSet::new()),
-            rublis_last_seen: Mutex::new(None),
+            watched_last_seen: Mutex::new(HashMap::new()),
+            follow_history: Mutex::new(FollowHistory::new()),
+            history: OnceLock::new(),
+            goal: Mutex::new(None),
             initial_follower_count: Mutex::new(None),
             last_known_follower_id: Mutex::new(None),
             recent_followers: Mutex::new(VecDeque::new()),
+            session_follower_count: Mutex::new(0),
             compressed_followers: Mutex::new(VecDeque::new()),
             api_cache: Mutex::new(APICache::new()),
-            client: reqwest::Client::new(),
+            chzzk: Arc::new(ChzzkClient::from_env()),
+            monitor: MonitorHandle::default(),
+            alerts: AlertQueue::default(),
+        }
+    }
+}
+
+impl AppState {
+    /// 지정한 치지직 API 구현을 사용하는 상태를 만듭니다. (목 서버, 테스트용)
+    pub fn with_chzzk_api(chzzk: Arc<dyn ChzzkApi>) -> Self {
+        Self {
+            chzzk,
+            ..Self::default()
+        }
+    }
+
+    /// 최근 팔로워 목록 맨 앞에 추가 (이미 있는 사용자는 앞으로 이동, 최대 50명)
+    pub fn push_recent_follower(&self, follower: FollowerItem) {
+        if let Ok(mut recent) = self.recent_followers.lock() {
+            recent.retain(|f| f.user.user_id_hash != follower.user.user_id_hash);
+            recent.push_front(follower);
+            recent.truncate(MAX_RECENT_FOLLOWERS);
+        }
+    }
+
+    /// 최근 팔로워 목록이 비어 있을 때만 채움 (첫 조회 결과, 최신순)
+    pub fn seed_recent_followers(&self, followers: &[FollowerItem]) {
+        if let Ok(mut recent) = self.recent_followers.lock() {
+            if recent.is_empty() {
+                recent.extend(followers.iter().take(MAX_RECENT_FOLLOWERS).cloned());
+            }
         }
     }
+
+    /// 다른 계정으로 로그인했을 때 이전 채널의 팔로워 상태 초기화
+    pub fn reset_channel_state(&self) {
+        if let Ok(mut recent) = self.recent_followers.lock() {
+            recent.clear();
+        }
+        if let Ok(mut history) = self.follow_history.lock() {
+            *history = FollowHistory::new();
+        }
+        if let Ok(mut watched) = self.watched_last_seen.lock() {
+            watched.clear();
+        }
+        if let Ok(mut cache) = self.api_cache.lock() {
+            cache.followers_cache = None;
+        }
+        if let Ok(mut count) = self.session_follower_count.lock() {
+            *count = 0;
+        }
+    }
+
+    /// 최근 팔로워 최대 `limit`명 (최신순)
+    pub fn recent_followers(&self, limit: usize) -> Vec<FollowerItem> {
+        self.recent_followers
+            .lock()
+            .map(|recent| recent.iter().take(limit).cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chzzk::test_support::follower_since;
+
+    #[test]
+    fn stable_hash_matches_fnv1a_reference_values() {
+        // 저장된 데이터와 호환되어야 하므로 값이 바뀌면 FOLLOWER_HASH_VERSION을 올려야 함
+        assert_eq!(stable_follower_hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(stable_follower_hash("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(stable_follower_hash("foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn parses_chzzk_and_rfc3339_timestamps() {
+        assert_eq!(parse_follow_timestamp("2024-01-01 09:00:00"), Ok(1_704_067_200));
+        assert_eq!(parse_follow_timestamp("2024-01-01T00:00:00Z"), Ok(1_704_067_200));
+    }
+
+    #[test]
+    fn recent_followers_are_newest_first_and_deduplicated() {
+        let state = AppState::default();
+        state.seed_recent_followers(&[follower_since("b", ""), follower_since("a", "")]);
+
+        state.push_recent_follower(follower_since("c", ""));
+        state.push_recent_follower(follower_since("a", ""));
+
+        let hashes: Vec<String> = state.recent_followers(10).into_iter().map(|f| f.user.user_id_hash).collect();
+        assert_eq!(hashes, ["a", "c", "b"]);
+        assert_eq!(state.recent_followers(1).len(), 1);
+
+        // 다른 계정으로 로그인하면 새 채널 목록과 세션 팔로워 수로 다시 시작
+        *state.session_follower_count.lock().unwrap() = 3;
+        state.reset_channel_state();
+        assert!(state.recent_followers(10).is_empty());
+        assert_eq!(*state.session_follower_count.lock().unwrap(), 0);
+        state.seed_recent_followers(&[follower_since("x", "")]);
+        assert_eq!(state.recent_followers(10).len(), 1);
+    }
+
+    #[test]
+    fn invalid_timestamp_is_recorded_instead_of_using_now() {
+        let before = timestamp_parse_failures();
+
+        let compressed = CompressedFollower::from_follower(&follower_since("a", "not a date"));
+
+        assert!(!compressed.has_timestamp());
+        assert!(compressed.matches_follower(&follower_since("a", "")));
+        assert!(timestamp_parse_failures() > before);
+        assert!(matches!(
+            CompressedFollower::try_from_follower(&follower_since("a", "not a date")),
+            Err(TimestampParseError::Invalid(_))
+        ));
+    }
 }
@@ -0,0 +1,282 @@
+//! 팔로워 통계
+//!
+//! 팔로워 기록 저장소(`history`)에 쌓인 이벤트로 일별/시간대별 팔로우 수,
+//! 언팔로우 수, 순증가, 재팔로우 비율, 팔로우가 가장 많았던 시간 구간을 계산합니다.
+
+use crate::history::{HistoryEventKind, HistoryRecord, HistoryStore};
+use chrono::{DateTime, FixedOffset, Offset, Timelike};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 기본 구간 길이 (분)
+pub const DEFAULT_WINDOW_MINUTES: u32 = 60;
+
+/// 구간 길이 범위 (분)
+pub const MIN_WINDOW_MINUTES: u32 = 5;
+pub const MAX_WINDOW_MINUTES: u32 = 24 * 60;
+
+/// 기본/최대 상위 구간 개수
+pub const DEFAULT_TOP_WINDOWS: usize = 5;
+pub const MAX_TOP_WINDOWS: usize = 50;
+
+/// 통계 조회 조건
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StatsQuery {
+    /// 시작 (RFC 3339 또는 "YYYY-MM-DD")
+    pub from: Option<String>,
+    /// 끝 (RFC 3339 또는 "YYYY-MM-DD", 날짜면 그날 끝까지 포함)
+    pub to: Option<String>,
+    /// 가장 바빴던 구간을 찾을 때의 구간 길이 (분)
+    #[serde(rename = "windowMinutes")]
+    pub window_minutes: Option<u32>,
+    /// 돌려줄 상위 구간 개수
+    pub top: Option<usize>,
+}
+
+/// 이벤트 종류별 개수
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventCounts {
+    pub follows: u32,
+    pub refollows: u32,
+    pub unfollows: u32,
+    /// 팔로우 + 재팔로우 - 언팔로우
+    pub net: i64,
+}
+
+impl EventCounts {
+    fn add(&mut self, kind: HistoryEventKind) {
+        match kind {
+            HistoryEventKind::Follow => self.follows += 1,
+            HistoryEventKind::Refollow => self.refollows += 1,
+            HistoryEventKind::Unfollow => self.unfollows += 1,
+        }
+        self.net = i64::from(self.follows) + i64::from(self.refollows) - i64::from(self.unfollows);
+    }
+
+    /// 팔로우 + 재팔로우
+    pub fn gained(&self) -> u32 {
+        self.follows + self.refollows
+    }
+}
+
+/// 일별 집계 (로컬 날짜 기준)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyStats {
+    pub date: String,
+    #[serde(flatten)]
+    pub counts: EventCounts,
+}
+
+/// 시간대별 집계 (0~23시, 로컬 시간 기준)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HourlyStats {
+    pub hour: u32,
+    #[serde(flatten)]
+    pub counts: EventCounts,
+}
+
+/// 팔로우가 몰린 시간 구간
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusyWindow {
+    pub start: String,
+    pub end: String,
+    #[serde(flatten)]
+    pub counts: EventCounts,
+}
+
+/// `GET /stats` 응답
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FollowerStats {
+    /// 기간 안의 첫/마지막 기록 시각 (기록이 없으면 null)
+    #[serde(rename = "firstEventAt")]
+    pub first_event_at: Option<String>,
+    #[serde(rename = "lastEventAt")]
+    pub last_event_at: Option<String>,
+    pub totals: EventCounts,
+    /// 팔로우 중 재팔로우 비율 (0~1, 팔로우가 없으면 0)
+    #[serde(rename = "refollowRate")]
+    pub refollow_rate: f64,
+    /// 하루 평균 순증가 (첫 기록부터 마지막 기록까지, 최소 1일 기준)
+    #[serde(rename = "netPerDay")]
+    pub net_per_day: f64,
+    #[serde(rename = "perDay")]
+    pub per_day: Vec<DailyStats>,
+    #[serde(rename = "perHour")]
+    pub per_hour: Vec<HourlyStats>,
+    #[serde(rename = "windowMinutes")]
+    pub window_minutes: u32,
+    #[serde(rename = "busiestWindows")]
+    pub busiest_windows: Vec<BusyWindow>,
+}
+
+impl FollowerStats {
+    /// 기록 목록(오래된 순)으로 통계 계산, 날짜/시간대는 `offset` 기준
+    pub fn from_records(records: &[HistoryRecord], window_minutes: u32, top: usize, offset: FixedOffset) -> Self {
+        let window_minutes = window_minutes.clamp(MIN_WINDOW_MINUTES, MAX_WINDOW_MINUTES);
+        let window_secs = i64::from(window_minutes) * 60;
+
+        let mut totals = EventCounts::default();
+        let mut per_day: BTreeMap<String, EventCounts> = BTreeMap::new();
+        let mut per_hour = [EventCounts::default(); 24];
+        let mut windows: BTreeMap<i64, EventCounts> = BTreeMap::new();
+        let mut first: Option<DateTime<FixedOffset>> = None;
+        let mut last: Option<DateTime<FixedOffset>> = None;
+
+        for record in records {
+            let Ok(detected_at) = DateTime::parse_from_rfc3339(&record.detected_at) else {
+                log::warn!("[Stats] 감지 시각을 읽을 수 없는 기록 무시: {}", record.id);
+                continue;
+            };
+            let local = detected_at.with_timezone(&offset);
+
+            totals.add(record.kind);
+            per_day.entry(local.format("%Y-%m-%d").to_string()).or_default().add(record.kind);
+            per_hour[local.hour() as usize].add(record.kind);
+
+            // 구간 시작 = 로컬 자정 기준으로 구간 길이에 맞춰 내림
+            let local_secs = local.timestamp() + i64::from(offset.local_minus_utc());
+            let window_start = local_secs - local_secs.rem_euclid(window_secs);
+            windows.entry(window_start).or_default().add(record.kind);
+
+            first = Some(first.map_or(detected_at, |f| f.min(detected_at)));
+            last = Some(last.map_or(detected_at, |l| l.max(detected_at)));
+        }
+
+        let refollow_rate = if totals.gained() > 0 {
+            f64::from(totals.refollows) / f64::from(totals.gained())
+        } else {
+            0.0
+        };
+
+        let days = match (first, last) {
+            (Some(first), Some(last)) => ((last - first).num_seconds() as f64 / 86_400.0).max(1.0),
+            _ => 1.0,
+        };
+
+        let mut busiest: Vec<(i64, EventCounts)> = windows.into_iter().filter(|(_, c)| c.gained() > 0).collect();
+        busiest.sort_by(|a, b| b.1.gained().cmp(&a.1.gained()).then(a.0.cmp(&b.0)));
+        busiest.truncate(top.min(MAX_TOP_WINDOWS));
+
+        let to_local = |local_secs: i64| {
+            DateTime::from_timestamp(local_secs - i64::from(offset.local_minus_utc()), 0)
+                .map(|dt| dt.with_timezone(&offset).to_rfc3339())
+                .unwrap_or_default()
+        };
+
+        Self {
+            first_event_at: first.map(|dt| dt.with_timezone(&offset).to_rfc3339()),
+            last_event_at: last.map(|dt| dt.with_timezone(&offset).to_rfc3339()),
+            totals,
+            refollow_rate,
+            net_per_day: totals.net as f64 / days,
+            per_day: per_day
+                .into_iter()
+                .map(|(date, counts)| DailyStats { date, counts })
+                .collect(),
+            per_hour: per_hour
+                .into_iter()
+                .enumerate()
+                .map(|(hour, counts)| HourlyStats {
+                    hour: hour as u32,
+                    counts,
+                })
+                .collect(),
+            window_minutes,
+            busiest_windows: busiest
+                .into_iter()
+                .map(|(start, counts)| BusyWindow {
+                    start: to_local(start),
+                    end: to_local(start + window_secs),
+                    counts,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// 기록 저장소에서 기간 안의 기록을 읽어 통계 계산 (로컬 시간대 기준)
+pub fn compute(store: &HistoryStore, query: &StatsQuery) -> Result<FollowerStats, String> {
+    let records = store.between(query.from.as_deref(), query.to.as_deref())?;
+    let offset = chrono::Local::now().offset().fix();
+
+    Ok(FollowerStats::from_records(
+        &records,
+        query.window_minutes.unwrap_or(DEFAULT_WINDOW_MINUTES),
+        query.top.unwrap_or(DEFAULT_TOP_WINDOWS),
+        offset,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chzzk::test_support::follower;
+
+    fn record(id: u64, kind: HistoryEventKind, detected_at: &str) -> HistoryRecord {
+        let name = format!("user{}", id);
+        HistoryRecord {
+            id,
+            kind,
+            user: follower(&name, &name).user,
+            following_since: String::new(),
+            detected_at: detected_at.to_string(),
+        }
+    }
+
+    fn kst() -> FixedOffset {
+        FixedOffset::east_opt(9 * 3600).unwrap()
+    }
+
+    #[test]
+    fn aggregates_by_local_day_and_hour() {
+        let records = vec![
+            record(1, HistoryEventKind::Follow, "2024-01-01T14:10:00Z"), // KST 1/1 23:10
+            record(2, HistoryEventKind::Refollow, "2024-01-01T15:05:00Z"), // KST 1/2 00:05
+            record(3, HistoryEventKind::Unfollow, "2024-01-01T15:30:00Z"),
+            record(4, HistoryEventKind::Follow, "2024-01-01T15:40:00Z"),
+        ];
+
+        let stats = FollowerStats::from_records(&records, 60, 5, kst());
+
+        assert_eq!(stats.totals.follows, 2);
+        assert_eq!(stats.totals.refollows, 1);
+        assert_eq!(stats.totals.unfollows, 1);
+        assert_eq!(stats.totals.net, 2);
+        assert!((stats.refollow_rate - 1.0 / 3.0).abs() < f64::EPSILON);
+        assert_eq!(stats.per_day.len(), 2);
+        assert_eq!(stats.per_day[0].date, "2024-01-01");
+        assert_eq!(stats.per_day[1].date, "2024-01-02");
+        assert_eq!(stats.per_day[1].counts.net, 1);
+        assert_eq!(stats.per_hour[23].counts.follows, 1);
+        assert_eq!(stats.per_hour[0].counts.gained(), 2);
+    }
+
+    #[test]
+    fn busiest_windows_are_sorted_by_follows() {
+        let records = vec![
+            record(1, HistoryEventKind::Follow, "2024-01-01T10:00:00Z"),
+            record(2, HistoryEventKind::Follow, "2024-01-01T12:00:00Z"),
+            record(3, HistoryEventKind::Follow, "2024-01-01T12:30:00Z"),
+            record(4, HistoryEventKind::Unfollow, "2024-01-01T13:00:00Z"),
+        ];
+
+        let stats = FollowerStats::from_records(&records, 60, 1, kst());
+
+        assert_eq!(stats.busiest_windows.len(), 1);
+        assert_eq!(stats.busiest_windows[0].start, "2024-01-01T21:00:00+09:00");
+        assert_eq!(stats.busiest_windows[0].end, "2024-01-01T22:00:00+09:00");
+        assert_eq!(stats.busiest_windows[0].counts.follows, 2);
+    }
+
+    #[test]
+    fn empty_history_has_zero_rates() {
+        let stats = FollowerStats::from_records(&[], DEFAULT_WINDOW_MINUTES, DEFAULT_TOP_WINDOWS, kst());
+
+        assert_eq!(stats.totals, EventCounts::default());
+        assert_eq!(stats.refollow_rate, 0.0);
+        assert_eq!(stats.net_per_day, 0.0);
+        assert!(stats.first_event_at.is_none());
+        assert_eq!(stats.per_hour.len(), 24);
+        assert!(stats.busiest_windows.is_empty());
+    }
+}
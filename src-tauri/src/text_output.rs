@@ -0,0 +1,290 @@
+//! OBS 텍스트(GDI+) 소스용 파일 출력
+//!
+//! 팔로우가 감지될 때마다 최근 팔로워, 세션 팔로워 수, 팔로우 목표를 텍스트 파일로 씁니다.
+//! 파일 내용은 설정의 `textOutputTemplates`로 바꿀 수 있고, OBS가 쓰는 도중의 파일을
+//! 읽지 않도록 임시 파일에 쓴 뒤 교체합니다.
+
+use crate::chzzk::FollowerItem;
+use crate::goal::GoalProgress;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+/// 기본 출력 폴더 이름 (실행 파일 옆, scripts 폴더와 같은 위치)
+const DEFAULT_FOLDER_NAME: &str = "text";
+
+/// 기본 최근 팔로워 표시 인원
+pub const DEFAULT_RECENT_COUNT: usize = 5;
+
+/// 출력 파일 이름
+pub const LATEST_FOLLOWER_FILE: &str = "latest_follower.txt";
+pub const RECENT_FOLLOWERS_FILE: &str = "recent_followers.txt";
+pub const SESSION_FOLLOWER_COUNT_FILE: &str = "session_follower_count.txt";
+pub const GOAL_FILE: &str = "goal.txt";
+
+/// 파일별 문구 템플릿
+///
+/// 팔로워: `{nickname}`, `{userIdHash}`, `{followingSince}`
+/// 세션 팔로워 수: `{count}`
+/// 목표: `{label}`, `{current}`, `{target}`, `{remaining}`, `{percent}`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct TextTemplates {
+    #[serde(rename = "latestFollower")]
+    pub latest_follower: String,
+    /// 최근 팔로워 한 명당 문구
+    #[serde(rename = "recentFollower")]
+    pub recent_follower: String,
+    /// 최근 팔로워 사이 구분자
+    #[serde(rename = "recentSeparator")]
+    pub recent_separator: String,
+    #[serde(rename = "sessionFollowerCount")]
+    pub session_follower_count: String,
+    pub goal: String,
+}
+
+impl Default for TextTemplates {
+    fn default() -> Self {
+        Self {
+            latest_follower: "{nickname}".to_string(),
+            recent_follower: "{nickname}".to_string(),
+            recent_separator: "\n".to_string(),
+            session_follower_count: "{count}".to_string(),
+            goal: "{label} {current}/{target}".to_string(),
+        }
+    }
+}
+
+/// 텍스트 출력 설정
+#[derive(Debug, Clone)]
+pub struct TextOutputConfig {
+    pub enabled: bool,
+    /// 출력 폴더 (설정이 비어 있으면 기본 폴더)
+    pub folder: Option<PathBuf>,
+    pub recent_count: usize,
+    pub templates: TextTemplates,
+}
+
+impl Default for TextOutputConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            folder: None,
+            recent_count: DEFAULT_RECENT_COUNT,
+            templates: TextTemplates::default(),
+        }
+    }
+}
+
+impl TextOutputConfig {
+    /// 설정 값(settings.json 전체 객체)에서 읽기
+    pub fn from_settings(settings: &serde_json::Value) -> Self {
+        let templates = settings
+            .get("textOutputTemplates")
+            .filter(|v| !v.is_null())
+            .and_then(|v| match serde_json::from_value(v.clone()) {
+                Ok(templates) => Some(templates),
+                Err(e) => {
+                    log::warn!("[TextOutput] 잘못된 템플릿 설정 무시: {}", e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            enabled: settings
+                .get("textOutputEnabled")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false),
+            folder: settings
+                .get("textOutputFolder")
+                .and_then(|v| v.as_str())
+                .map(str::trim)
+                .filter(|folder| !folder.is_empty())
+                .map(PathBuf::from),
+            recent_count: settings
+                .get("textOutputRecentCount")
+                .and_then(serde_json::Value::as_u64)
+                .map(|count| (count as usize).clamp(1, crate::state::MAX_RECENT_FOLLOWERS))
+                .unwrap_or(DEFAULT_RECENT_COUNT),
+            templates,
+        }
+    }
+
+    /// 실제로 쓸 출력 폴더
+    pub fn output_folder(&self) -> Option<PathBuf> {
+        self.folder.clone().or_else(default_folder)
+    }
+}
+
+/// 기본 출력 폴더 (실행 파일 옆의 `text` 폴더)
+pub fn default_folder() -> Option<PathBuf> {
+    let exe_path = std::env::current_exe().ok()?;
+    Some(exe_path.parent()?.join(DEFAULT_FOLDER_NAME))
+}
+
+/// 설정 스토어에서 텍스트 출력 설정 읽기
+pub fn read_config(app_handle: &AppHandle) -> TextOutputConfig {
+    use tauri_plugin_store::StoreExt;
+
+    let Ok(store) = app_handle.store("settings.json") else {
+        return TextOutputConfig::default();
+    };
+
+    let settings: serde_json::Map<String, serde_json::Value> = [
+        "textOutputEnabled",
+        "textOutputFolder",
+        "textOutputRecentCount",
+        "textOutputTemplates",
+    ]
+    .into_iter()
+    .filter_map(|key| store.get(key).map(|value| (key.to_string(), value)))
+    .collect();
+
+    TextOutputConfig::from_settings(&serde_json::Value::Object(settings))
+}
+
+/// 파일로 쓸 현재 값
+pub struct TextSnapshot<'a> {
+    /// 최근 팔로워 (최신순, 첫 번째가 마지막 팔로워)
+    pub recent: &'a [FollowerItem],
+    pub session_follower_count: u64,
+    pub goal: Option<&'a GoalProgress>,
+}
+
+/// 템플릿을 적용해 파일 이름별 내용 생성
+pub fn render(config: &TextOutputConfig, snapshot: &TextSnapshot) -> Vec<(&'static str, String)> {
+    let templates = &config.templates;
+
+    let latest = snapshot
+        .recent
+        .first()
+        .map(|follower| render_follower(&templates.latest_follower, follower))
+        .unwrap_or_default();
+
+    let recent = snapshot
+        .recent
+        .iter()
+        .take(config.recent_count)
+        .map(|follower| render_follower(&templates.recent_follower, follower))
+        .collect::<Vec<_>>()
+        .join(&templates.recent_separator);
+
+    let session_count = templates
+        .session_follower_count
+        .replace("{count}", &snapshot.session_follower_count.to_string());
+
+    // 목표가 없으면 빈 파일
+    let goal = snapshot
+        .goal
+        .map(|progress| {
+            templates
+                .goal
+                .replace("{label}", &progress.goal.label)
+                .replace("{current}", &progress.goal.current_count.to_string())
+                .replace("{target}", &progress.goal.target.to_string())
+                .replace("{remaining}", &progress.remaining.to_string())
+                .replace("{percent}", &format!("{:.0}", progress.percent))
+        })
+        .unwrap_or_default();
+
+    vec![
+        (LATEST_FOLLOWER_FILE, latest),
+        (RECENT_FOLLOWERS_FILE, recent),
+        (SESSION_FOLLOWER_COUNT_FILE, session_count),
+        (GOAL_FILE, goal),
+    ]
+}
+
+fn render_follower(template: &str, follower: &FollowerItem) -> String {
+    template
+        .replace("{nickname}", &follower.user.nickname)
+        .replace("{userIdHash}", &follower.user.user_id_hash)
+        .replace("{followingSince}", &follower.following_since)
+}
+
+/// 폴더에 파일들을 씀 (임시 파일에 쓴 뒤 교체)
+pub fn write_files(folder: &Path, files: &[(&str, String)]) -> Result<(), String> {
+    std::fs::create_dir_all(folder).map_err(|e| format!("폴더 생성 실패: {}", e))?;
+
+    for (name, content) in files {
+        let path = folder.join(name);
+        let tmp_path = path.with_extension("txt.tmp");
+        std::fs::write(&tmp_path, content).map_err(|e| format!("임시 파일 쓰기 실패 ({}): {}", name, e))?;
+        std::fs::rename(&tmp_path, &path).map_err(|e| format!("파일 교체 실패 ({}): {}", name, e))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chzzk::test_support::follower;
+    use crate::goal::FollowGoal;
+
+    #[test]
+    fn renders_default_templates() {
+        let recent = vec![follower("c", "c"), follower("b", "b"), follower("a", "a")];
+        let config = TextOutputConfig {
+            recent_count: 2,
+            ..TextOutputConfig::default()
+        };
+        let snapshot = TextSnapshot {
+            recent: &recent,
+            session_follower_count: 3,
+            goal: None,
+        };
+
+        let files = render(&config, &snapshot);
+
+        assert_eq!(
+            files,
+            vec![
+                (LATEST_FOLLOWER_FILE, "c".to_string()),
+                (RECENT_FOLLOWERS_FILE, "c\nb".to_string()),
+                (SESSION_FOLLOWER_COUNT_FILE, "3".to_string()),
+                (GOAL_FILE, String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_custom_templates_from_settings() {
+        let config = TextOutputConfig::from_settings(&serde_json::json!({
+            "textOutputEnabled": true,
+            "textOutputFolder": "  ",
+            "textOutputTemplates": { "goal": "{label}: {percent}%", "recentSeparator": " | " }
+        }));
+        let mut goal = FollowGoal::new(Some("목표".to_string()), 10, 0);
+        goal.apply(5, 0);
+        let progress = goal.progress();
+        let recent = vec![follower("b", "b"), follower("a", "a")];
+
+        let files = render(
+            &config,
+            &TextSnapshot {
+                recent: &recent,
+                session_follower_count: 0,
+                goal: Some(&progress),
+            },
+        );
+
+        assert!(config.enabled);
+        assert!(config.folder.is_none());
+        assert_eq!(config.templates.latest_follower, "{nickname}");
+        assert_eq!(files[1].1, "b | a");
+        assert_eq!(files[3].1, "목표: 50%");
+    }
+
+    #[test]
+    fn writes_files_atomically() {
+        let folder = std::env::temp_dir().join(format!("fazzk-text-output-{}", std::process::id()));
+
+        write_files(&folder, &[(LATEST_FOLLOWER_FILE, "닉네임".to_string())]).unwrap();
+
+        assert_eq!(std::fs::read_to_string(folder.join(LATEST_FOLLOWER_FILE)).unwrap(), "닉네임");
+        assert!(!folder.join("latest_follower.txt.tmp").exists());
+        let _ = std::fs::remove_dir_all(&folder);
+    }
+}
@@ -0,0 +1,168 @@
+//! VIP/관심 팔로워 목록
+//!
+//! 설정의 `watchList`에 등록한 사용자(userIdHash 또는 닉네임)별로
+//! 팔로우/언팔로우 알림 여부, 전용 사운드/문구, 쿨다운 무시 여부를 지정합니다.
+
+use crate::chzzk::FollowerItem;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+/// 관심 팔로워 한 명의 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchEntry {
+    #[serde(rename = "userIdHash", default)]
+    pub user_id_hash: Option<String>,
+    #[serde(default)]
+    pub nickname: Option<String>,
+    /// 팔로우/재팔로우 시 전용 알림 (false면 일반 팔로워와 같은 알림)
+    #[serde(rename = "alertOnFollow", default = "default_true")]
+    pub alert_on_follow: bool,
+    /// 언팔로우 시 알림
+    #[serde(rename = "alertOnUnfollow", default)]
+    pub alert_on_unfollow: bool,
+    /// 전용 알림 사운드 경로
+    #[serde(default)]
+    pub sound: Option<String>,
+    /// 전용 알림 문구 (`{nickname}` 치환)
+    #[serde(default)]
+    pub template: Option<String>,
+    /// 재팔로우 쿨다운 등 알림 제한 무시
+    #[serde(rename = "bypassCooldown", default)]
+    pub bypass_cooldown: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl WatchEntry {
+    /// userIdHash가 있으면 해시로, 없으면 닉네임으로 비교
+    pub fn matches(&self, follower: &FollowerItem) -> bool {
+        match (&self.user_id_hash, &self.nickname) {
+            (Some(hash), _) => *hash == follower.user.user_id_hash,
+            (None, Some(nickname)) => *nickname == follower.user.nickname,
+            (None, None) => false,
+        }
+    }
+
+    /// 마지막 확인 시각을 기록할 때 쓰는 키
+    pub fn key(&self) -> String {
+        match (&self.user_id_hash, &self.nickname) {
+            (Some(hash), _) => hash.clone(),
+            (None, Some(nickname)) => format!("nickname:{}", nickname),
+            (None, None) => String::new(),
+        }
+    }
+
+    /// 오버레이에 전달할 알림 옵션
+    pub fn alert(&self) -> WatchAlert {
+        WatchAlert {
+            sound: self.sound.clone(),
+            template: self.template.clone(),
+        }
+    }
+}
+
+/// 관심 팔로워 알림에 함께 보내는 옵션
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WatchAlert {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sound: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+/// 관심 팔로워 목록
+#[derive(Debug, Clone, Default)]
+pub struct WatchList {
+    entries: Vec<WatchEntry>,
+}
+
+impl WatchList {
+    /// 설정 값(`watchList` 배열)에서 목록 생성, 잘못된 항목은 건너뜀
+    pub fn from_setting(value: &serde_json::Value) -> Self {
+        let entries = value
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| match serde_json::from_value::<WatchEntry>(item.clone()) {
+                        Ok(entry) if entry.user_id_hash.is_some() || entry.nickname.is_some() => Some(entry),
+                        Ok(_) => {
+                            log::warn!("[WatchList] userIdHash나 nickname이 없는 항목은 무시합니다");
+                            None
+                        }
+                        Err(e) => {
+                            log::warn!("[WatchList] 잘못된 항목 무시: {}", e);
+                            None
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { entries }
+    }
+
+    pub fn find(&self, follower: &FollowerItem) -> Option<&WatchEntry> {
+        self.entries.iter().find(|entry| entry.matches(follower))
+    }
+
+    pub fn entries(&self) -> &[WatchEntry] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// 설정 스토어에서 `watchList` 읽기 (저장된 값이 없으면 빈 목록)
+pub fn read_watch_list(app_handle: &AppHandle) -> WatchList {
+    use tauri_plugin_store::StoreExt;
+
+    app_handle
+        .store("settings.json")
+        .ok()
+        .and_then(|store| store.get("watchList"))
+        .map(|value| WatchList::from_setting(&value))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chzzk::test_support::follower;
+
+    #[test]
+    fn matches_by_hash_before_nickname() {
+        let list = WatchList::from_setting(&serde_json::json!([
+            { "userIdHash": "abc", "nickname": "예전닉네임" },
+            { "nickname": "단골" }
+        ]));
+
+        // 해시가 있으면 닉네임이 바뀌어도 해시로만 비교
+        assert!(list.entries()[0].matches(&follower("abc", "새닉네임")));
+        assert!(!list.entries()[0].matches(&follower("other", "예전닉네임")));
+        assert!(list.entries()[1].matches(&follower("any", "단골")));
+        assert_eq!(list.entries()[1].key(), "nickname:단골");
+        assert!(list.find(&follower("none", "모르는사람")).is_none());
+    }
+
+    #[test]
+    fn from_setting_skips_invalid_entries_and_applies_defaults() {
+        let list = WatchList::from_setting(&serde_json::json!([
+            { "alertOnUnfollow": true },
+            { "nickname": 1 },
+            { "nickname": "단골", "sound": "vip.mp3" }
+        ]));
+
+        assert_eq!(list.entries().len(), 1);
+        let entry = &list.entries()[0];
+        assert!(entry.alert_on_follow);
+        assert!(!entry.alert_on_unfollow);
+        assert!(!entry.bypass_cooldown);
+        assert_eq!(entry.alert().sound.as_deref(), Some("vip.mp3"));
+        assert!(WatchList::from_setting(&serde_json::json!("not a list")).is_empty());
+    }
+}
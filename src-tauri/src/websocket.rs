@@ -1,17 +1,35 @@
 use axum::{
     extract::{
         ws::{Message, WebSocket, WebSocketUpgrade},
-        State,
+        Query, State,
     },
 };
 use futures_util::{sink::SinkExt, stream::StreamExt};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use tokio::sync::{broadcast, RwLock};
+use tokio::sync::{broadcast, Notify, RwLock};
 use uuid::Uuid;
 
+/// WebSocket 메시지 형식 버전 (`hello` 메시지로 전달)
+pub const WS_PROTOCOL_VERSION: u32 = 1;
+
+/// `hello` 메시지에 담는 최근 팔로워 수
+const HELLO_RECENT_FOLLOWERS: usize = 10;
+
+/// 재연결한 클라이언트에 다시 보낼 수 있도록 보관하는 최근 브로드캐스트 이벤트 수
+pub const REPLAY_BUFFER_SIZE: usize = 500;
+
+/// 구독할 수 있는 토픽 (기본 구독과 `"*"`는 이 목록 전체)
+pub const WS_TOPICS: [&str; 7] = ["followers", "alerts", "test", "settings", "goal", "stats", "system"];
+
+/// `?resume=1`로 연결한 클라이언트의 resume 요청을 기다리며 실시간 이벤트를 잡아 두는 최대 시간
+const RESUME_WAIT: Duration = Duration::from_secs(2);
+
+/// 이름을 지정해야만 받는 토픽 (방송 화면에 나가면 안 되는 언팔로우 등)
+pub const PRIVATE_TOPICS: [&str; 1] = ["unfollows"];
+
 // WebSocket 메시지 타입
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -21,40 +39,269 @@ pub enum WSMessage {
     Ping,
     #[serde(rename = "subscribe")]
     Subscribe { topics: Vec<String> },
+    #[serde(rename = "unsubscribe")]
+    Unsubscribe { topics: Vec<String> },
+    // 마지막으로 받은 순번 이후의 이벤트 재전송 요청 (재연결 직후)
+    #[serde(rename = "resume")]
+    Resume {
+        #[serde(rename = "lastSeq", alias = "last_seq")]
+        last_seq: u64,
+    },
     #[serde(rename = "test_follower")]
-    TestFollower,
+    TestFollower {
+        // true면 요청한 클라이언트에만 테스트 알림 전송
+        #[serde(default)]
+        local: bool,
+    },
+    // 오버레이가 알림 표시를 마침
+    #[serde(rename = "alert_done")]
+    AlertDone { id: u64 },
+    // 알림 큐 제어 (replay는 id가 없으면 마지막 알림)
+    #[serde(rename = "alert_control")]
+    AlertControl {
+        action: crate::alert_queue::AlertAction,
+        #[serde(default)]
+        id: Option<u64>,
+    },
     
     // 서버 → 클라이언트
     #[serde(rename = "pong")]
     Pong,
+    // 연결 직후 현재 상태 (설정, 로그인, 모니터, 최근 팔로워, 목표)
+    #[serde(rename = "hello")]
+    Hello {
+        #[serde(rename = "protocolVersion")]
+        protocol_version: u32,
+        #[serde(rename = "clientId")]
+        client_id: String,
+        settings: serde_json::Value,
+        #[serde(rename = "loggedIn")]
+        logged_in: bool,
+        nickname: Option<String>,
+        monitor: crate::monitor::MonitorStatus,
+        #[serde(rename = "recentFollowers")]
+        recent_followers: Vec<crate::chzzk::FollowerItem>,
+        goal: Option<crate::goal::GoalProgress>,
+        // 연결 시점의 마지막 이벤트 순번 (이후 이벤트는 실시간으로 전달)
+        #[serde(rename = "lastSeq")]
+        last_seq: u64,
+        // 서버 실행마다 바뀌는 값 (바뀌었으면 순번이 처음부터 다시 시작된 것이므로 resume하지 않음)
+        epoch: u64,
+    },
+    #[serde(rename = "resumed")]
+    Resumed {
+        // 다시 보낸 이벤트 수
+        replayed: usize,
+        // false면 일부 이벤트가 이미 버퍼에서 밀려나 재전송하지 못함
+        complete: bool,
+    },
+    #[serde(rename = "subscribed")]
+    Subscribed {
+        // 현재 구독 중인 토픽 전체
+        topics: Vec<String>,
+        // 알 수 없어서 무시한 토픽
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        unknown: Vec<String>,
+    },
     #[serde(rename = "new_follower")]
-    NewFollower { follower: crate::chzzk::FollowerItem },
+    NewFollower {
+        follower: crate::chzzk::FollowerItem,
+        // 관심 팔로워 전용 사운드/문구
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        alert: Option<crate::watchlist::WatchAlert>,
+        // 이전에 팔로우한 적이 있는 사용자
+        #[serde(rename = "isRefollow", default)]
+        is_refollow: bool,
+        // 재팔로우 쿨다운 중 - 오버레이는 알림을 띄우지 않음
+        #[serde(default)]
+        silent: bool,
+    },
+    // 알림 큐에서 표시할 차례가 된 알림
+    #[serde(rename = "alert")]
+    Alert { alert: crate::alert_queue::Alert },
+    // 표시 중인 알림을 건너뜀 - 오버레이는 바로 닫음
+    #[serde(rename = "alert_skipped")]
+    AlertSkipped { id: u64 },
+    // alert_control 응답
+    #[serde(rename = "alert_queue")]
+    AlertQueue { status: crate::alert_queue::AlertQueueStatus },
     #[serde(rename = "test_notification")]
     TestNotification { follower: crate::chzzk::FollowerItem },
     #[serde(rename = "settings_updated")]
     SettingsUpdated { settings: serde_json::Value },
+    #[serde(rename = "watched_unfollow")]
+    WatchedUnfollow {
+        follower: crate::chzzk::FollowerItem,
+        alert: crate::watchlist::WatchAlert,
+    },
+    #[serde(rename = "unfollow")]
+    FollowerLost {
+        follower: crate::chzzk::FollowerItem,
+        // 팔로우 유지 기간 (followingSince를 알 수 없으면 null)
+        #[serde(rename = "followedForSecs")]
+        followed_for_secs: Option<i64>,
+        #[serde(rename = "unfollowedAt")]
+        unfollowed_at: String,
+    },
+    #[serde(rename = "session_expired")]
+    SessionExpired { reason: String },
+    #[serde(rename = "session_restored")]
+    SessionRestored,
+    #[serde(rename = "monitor_status")]
+    MonitorStatus { status: crate::monitor::MonitorStatus },
+    #[serde(rename = "recent_followers")]
+    RecentFollowers { followers: Vec<crate::chzzk::FollowerItem> }, // 최신순
+    #[serde(rename = "goal_progress")]
+    GoalProgress { goal: Option<crate::goal::GoalProgress> }, // 목표가 삭제되면 null
+    #[serde(rename = "stats_updated")]
+    StatsUpdated { stats: crate::stats::FollowerStats }, // GET /stats 기본 조회와 같은 값
     #[serde(rename = "error")]
     Error { message: String },
 }
 
+impl WSMessage {
+    /// 이 메시지를 받을 토픽 (None이면 구독과 관계없이 전달)
+    pub fn topic(&self) -> Option<&'static str> {
+        match self {
+            WSMessage::NewFollower { .. } | WSMessage::RecentFollowers { .. } => Some("followers"),
+            // 관심 팔로워 언팔로우는 설정한 사람에게 알리는 용도라 오버레이로 전달하고,
+            // 일반 언팔로우만 방송 화면에 나가지 않도록 비공개 토픽으로 보냄
+            WSMessage::FollowerLost { .. } => Some("unfollows"),
+            WSMessage::Alert { .. } | WSMessage::AlertSkipped { .. } | WSMessage::WatchedUnfollow { .. } => {
+                Some("alerts")
+            }
+            WSMessage::TestNotification { .. } => Some("test"),
+            WSMessage::SettingsUpdated { .. } => Some("settings"),
+            WSMessage::GoalProgress { .. } => Some("goal"),
+            WSMessage::StatsUpdated { .. } => Some("stats"),
+            WSMessage::SessionExpired { .. }
+            | WSMessage::SessionRestored
+            | WSMessage::MonitorStatus { .. }
+            | WSMessage::Error { .. } => Some("system"),
+            WSMessage::Ping
+            | WSMessage::Subscribe { .. }
+            | WSMessage::Unsubscribe { .. }
+            | WSMessage::Resume { .. }
+            | WSMessage::TestFollower { .. }
+            | WSMessage::AlertDone { .. }
+            | WSMessage::AlertControl { .. }
+            | WSMessage::AlertQueue { .. }
+            | WSMessage::Pong
+            | WSMessage::Hello { .. }
+            | WSMessage::Resumed { .. }
+            | WSMessage::Subscribed { .. } => None,
+        }
+    }
+
+    /// 재연결/밀림 복구 때 다시 보낼지 여부
+    ///
+    /// 알림은 아직 표시 중(완료 응답 전)인 것만 다시 보냅니다. 끝난 알림을 다시 보내면 이미 표시한
+    /// 알림이 또 뜨고, 대기 중인 알림은 차례가 되면 디스패처가 보냅니다.
+    pub fn replayable(&self, alerts: &crate::alert_queue::AlertQueue) -> bool {
+        match self {
+            WSMessage::Alert { alert } => alerts.is_current(alert.id),
+            // 건너뛴 알림은 이미 끝났으므로 닫을 알림도 없음
+            WSMessage::AlertSkipped { .. } => false,
+            _ => true,
+        }
+    }
+}
+
+/// 클라이언트로 나가는 메시지 (브로드캐스트 이벤트에는 순번이 붙고, 개별 응답에는 없음)
+#[derive(Debug, Clone, Serialize)]
+pub struct WSEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq: Option<u64>,
+    #[serde(flatten)]
+    pub message: WSMessage,
+}
+
+// 최근 브로드캐스트 이벤트 보관 (오래된 것부터 밀려남)
+#[derive(Debug)]
+struct ReplayBuffer {
+    last_seq: u64,
+    events: VecDeque<WSEvent>,
+    capacity: usize,
+}
+
+impl ReplayBuffer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            last_seq: 0,
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    // 다음 순번을 붙여 보관
+    fn push(&mut self, message: WSMessage) -> WSEvent {
+        self.last_seq += 1;
+        let event = WSEvent { seq: Some(self.last_seq), message };
+        if self.events.len() >= self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(event.clone());
+        event
+    }
+
+    // after < seq <= until 인 이벤트와, 그 구간을 빠짐없이 찾았는지 여부
+    fn between(&self, after: u64, until: u64) -> (Vec<WSEvent>, bool) {
+        // 서버가 다시 시작되어 순번이 처음부터인 경우
+        if after > self.last_seq {
+            return (Vec::new(), false);
+        }
+
+        let until = until.min(self.last_seq);
+        let events: Vec<WSEvent> = self
+            .events
+            .iter()
+            .filter(|event| event.seq.is_some_and(|seq| seq > after && seq <= until))
+            .cloned()
+            .collect();
+        let oldest = self.events.front().and_then(|event| event.seq).unwrap_or(self.last_seq + 1);
+
+        (events, after >= until || oldest <= after + 1)
+    }
+}
+
+// 토픽 이름 확인 ("*"는 비공개 토픽을 뺀 전체로 펼침), 알 수 없는 토픽은 두 번째 목록으로
+fn resolve_topics(topics: &[String]) -> (Vec<String>, Vec<String>) {
+    let mut known = Vec::new();
+    let mut unknown = Vec::new();
+    for topic in topics {
+        if topic == "*" {
+            known.extend(WS_TOPICS.map(ToString::to_string));
+        } else if WS_TOPICS.contains(&topic.as_str()) || PRIVATE_TOPICS.contains(&topic.as_str()) {
+            known.push(topic.clone());
+        } else {
+            unknown.push(topic.clone());
+        }
+    }
+    (known, unknown)
+}
+
 // 클라이언트 정보 (개선된 버전)
 #[derive(Debug, Clone)]
 pub struct WSClient {
     pub id: String,
     pub subscriptions: Vec<String>,
-    pub sender: broadcast::Sender<WSMessage>,
+    // false면 아직 구독 요청이 없어 기본값(전체 토픽)을 쓰는 중
+    pub explicit_subscriptions: bool,
+    pub sender: broadcast::Sender<WSEvent>,
     pub connected_at: Instant,
     pub last_activity: Instant,
 }
 
 impl WSClient {
     pub fn new(id: String) -> Self {
-        let (sender, _) = broadcast::channel(100);
+        // 재전송 이벤트가 한 번에 들어가도록 버퍼 크기만큼 여유를 둠
+        let (sender, _) = broadcast::channel(REPLAY_BUFFER_SIZE + 100);
         let now = Instant::now();
         
         Self {
             id,
-            subscriptions: vec!["followers".to_string()], // 기본 구독
+            subscriptions: WS_TOPICS.map(ToString::to_string).to_vec(), // 기본 구독 (전체)
+            explicit_subscriptions: false,
             sender,
             connected_at: now,
             last_activity: now,
@@ -68,6 +315,29 @@ impl WSClient {
     pub fn is_stale(&self, timeout: Duration) -> bool {
         self.last_activity.elapsed() > timeout
     }
+    
+    /// 토픽 구독 (첫 구독 요청은 기본값인 전체 구독을 대체)
+    pub fn subscribe(&mut self, topics: Vec<String>) {
+        if !self.explicit_subscriptions {
+            self.subscriptions.clear();
+            self.explicit_subscriptions = true;
+        }
+        for topic in topics {
+            if !self.subscriptions.contains(&topic) {
+                self.subscriptions.push(topic);
+            }
+        }
+    }
+    
+    /// 토픽 구독 해제
+    pub fn unsubscribe(&mut self, topics: &[String]) {
+        self.explicit_subscriptions = true;
+        self.subscriptions.retain(|topic| !topics.contains(topic));
+    }
+    
+    pub fn is_subscribed(&self, topic: &str) -> bool {
+        self.subscriptions.iter().any(|t| t == topic)
+    }
 }
 
 // WebSocket 연결 풀 (메모리 효율성 향상)
@@ -77,7 +347,19 @@ pub struct WSConnectionPool {
     connection_limit: usize,
     cleanup_interval: Duration,
     client_timeout: Duration,
-    broadcast_tx: broadcast::Sender<WSMessage>,
+    broadcast_tx: broadcast::Sender<WSEvent>,
+    // 순번 부여와 전송을 같은 잠금 안에서 처리해 순번 순서대로 전달
+    replay: Arc<Mutex<ReplayBuffer>>,
+    // 순번 공간 구분용 값 (풀 생성 시각, 밀리초)
+    epoch: u64,
+    // 클라이언트 연결/해제, 구독 변경 알림 (알림 큐가 구독자를 기다릴 때 사용)
+    clients_changed: Arc<Notify>,
+}
+
+impl Default for WSConnectionPool {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl WSConnectionPool {
@@ -90,6 +372,9 @@ impl WSConnectionPool {
             cleanup_interval: Duration::from_secs(300), // 5분마다 정리
             client_timeout: Duration::from_secs(600), // 10분 비활성 시 제거
             broadcast_tx,
+            replay: Arc::new(Mutex::new(ReplayBuffer::new(REPLAY_BUFFER_SIZE))),
+            epoch: u64::try_from(chrono::Utc::now().timestamp_millis()).unwrap_or_default(),
+            clients_changed: Arc::new(Notify::new()),
         }
     }
     
@@ -111,6 +396,7 @@ impl WSConnectionPool {
         let client_id = client.id.clone();
         clients.insert(client_id.clone(), client);
         log::info!("[WSPool] Client added: {} (Total: {})", client_id, clients.len());
+        self.clients_changed.notify_waiters();
         
         Ok(())
     }
@@ -120,6 +406,7 @@ impl WSConnectionPool {
         let mut clients = self.clients.write().await;
         if clients.remove(client_id).is_some() {
             log::info!("[WSPool] Client removed: {} (Remaining: {})", client_id, clients.len());
+            self.clients_changed.notify_waiters();
         }
     }
     
@@ -131,6 +418,63 @@ impl WSConnectionPool {
         }
     }
     
+    // 클라이언트 구독 변경 후 현재 구독 목록 반환
+    pub async fn update_subscriptions(
+        &self,
+        client_id: &str,
+        update: impl FnOnce(&mut WSClient),
+    ) -> Option<Vec<String>> {
+        let mut clients = self.clients.write().await;
+        let client = clients.get_mut(client_id)?;
+        update(client);
+        self.clients_changed.notify_waiters();
+        Some(client.subscriptions.clone())
+    }
+    
+    // 특정 클라이언트에만 메시지 전송 (구독 토픽과 관계없이 전달)
+    pub async fn send_to(&self, client_id: &str, message: WSMessage) -> Result<(), String> {
+        self.send_event_to(client_id, WSEvent { seq: None, message }).await
+    }
+    
+    async fn send_event_to(&self, client_id: &str, event: WSEvent) -> Result<(), String> {
+        let clients = self.clients.read().await;
+        let client = clients
+            .get(client_id)
+            .ok_or_else(|| format!("Unknown client: {}", client_id))?;
+        client
+            .sender
+            .send(event)
+            .map(|_| ())
+            .map_err(|e| format!("Failed to send to client {}: {}", client_id, e))
+    }
+    
+    // 순번을 붙여 재전송 버퍼에 보관한 뒤 브로드캐스트 (연결된 클라이언트가 없어도 보관됨)
+    fn publish(&self, message: WSMessage) -> Result<usize, String> {
+        let mut replay = self.replay.lock().map_err(|e| e.to_string())?;
+        let event = replay.push(message);
+        self.broadcast_tx.send(event).map_err(|e| e.to_string())
+    }
+    
+    // after < seq <= until 인 보관 이벤트 (두 번째 값이 false면 일부가 이미 밀려남)
+    pub fn replay_between(&self, after: u64, until: u64) -> (Vec<WSEvent>, bool) {
+        match self.replay.lock() {
+            Ok(replay) => replay.between(after, until),
+            Err(_) => (Vec::new(), false),
+        }
+    }
+    
+    // 메시지를 이 클라이언트에 보낼지 확인 (토픽이 없는 메시지는 항상 전달)
+    pub async fn should_deliver(&self, client_id: &str, message: &WSMessage) -> bool {
+        let Some(topic) = message.topic() else {
+            return true;
+        };
+        self.clients
+            .read()
+            .await
+            .get(client_id)
+            .is_none_or(|client| client.is_subscribed(topic))
+    }
+    
     // 비활성 클라이언트 정리
     pub async fn cleanup_stale_clients(&self) {
         let mut clients = self.clients.write().await;
@@ -145,6 +489,7 @@ impl WSConnectionPool {
         for id in to_remove {
             clients.remove(&id);
             log::info!("[WSPool] Removed stale client: {}", id);
+            self.clients_changed.notify_waiters();
         }
         
         if !clients.is_empty() {
@@ -164,10 +509,50 @@ impl WSConnectionPool {
         self.clients.read().await.len()
     }
     
+    // 토픽을 구독 중인 클라이언트 수
+    pub async fn subscriber_count(&self, topic: &str) -> usize {
+        self.clients
+            .read()
+            .await
+            .values()
+            .filter(|client| client.is_subscribed(topic))
+            .count()
+    }
+    
+    // 토픽을 구독한 클라이언트가 생길 때까지 대기
+    pub async fn wait_for_subscriber(&self, topic: &str) {
+        loop {
+            // 확인과 대기 사이에 들어온 변경을 놓치지 않도록 먼저 등록
+            let changed = self.clients_changed.notified();
+            tokio::pin!(changed);
+            changed.as_mut().enable();
+            
+            if self.subscriber_count(topic).await > 0 {
+                return;
+            }
+            changed.await;
+        }
+    }
+    
+    // 이 클라이언트가 토픽을 구독 중인지 (연결되지 않은 클라이언트는 false)
+    pub async fn is_client_subscribed(&self, client_id: &str, topic: &str) -> bool {
+        self.clients
+            .read()
+            .await
+            .get(client_id)
+            .is_some_and(|client| client.is_subscribed(topic))
+    }
+    
     // 새 팔로워 브로드캐스트
-    pub async fn broadcast_new_follower(&self, follower: crate::chzzk::FollowerItem) {
-        let message = WSMessage::NewFollower { follower };
-        if let Err(e) = self.broadcast_tx.send(message) {
+    pub async fn broadcast_new_follower(
+        &self,
+        follower: crate::chzzk::FollowerItem,
+        alert: Option<crate::watchlist::WatchAlert>,
+        is_refollow: bool,
+        silent: bool,
+    ) {
+        let message = WSMessage::NewFollower { follower, alert, is_refollow, silent };
+        if let Err(e) = self.publish(message) {
             log::warn!("[WSPool] Failed to broadcast new follower: {}", e);
         } else {
             let client_count = self.client_count().await;
@@ -175,10 +560,41 @@ impl WSConnectionPool {
         }
     }
     
+    // 관심 팔로워 언팔로우 브로드캐스트
+    pub async fn broadcast_watched_unfollow(
+        &self,
+        follower: crate::chzzk::FollowerItem,
+        alert: crate::watchlist::WatchAlert,
+    ) {
+        let message = WSMessage::WatchedUnfollow { follower, alert };
+        if let Err(e) = self.publish(message) {
+            log::warn!("[WSPool] Failed to broadcast watched unfollow: {}", e);
+        } else {
+            let client_count = self.client_count().await;
+            log::info!("[WSPool] Broadcasted watched unfollow to {} clients", client_count);
+        }
+    }
+    
+    // 언팔로우 브로드캐스트
+    pub async fn broadcast_follower_lost(
+        &self,
+        follower: crate::chzzk::FollowerItem,
+        followed_for_secs: Option<i64>,
+        unfollowed_at: String,
+    ) {
+        let message = WSMessage::FollowerLost { follower, followed_for_secs, unfollowed_at };
+        if let Err(e) = self.publish(message) {
+            log::warn!("[WSPool] Failed to broadcast follower lost: {}", e);
+        } else {
+            let client_count = self.client_count().await;
+            log::info!("[WSPool] Broadcasted follower lost to {} clients", client_count);
+        }
+    }
+    
     // 테스트 알림 브로드캐스트
     pub async fn broadcast_test_notification(&self, follower: crate::chzzk::FollowerItem) {
         let message = WSMessage::TestNotification { follower };
-        if let Err(e) = self.broadcast_tx.send(message) {
+        if let Err(e) = self.publish(message) {
             log::warn!("[WSPool] Failed to broadcast test notification: {}", e);
         } else {
             let client_count = self.client_count().await;
@@ -189,7 +605,7 @@ impl WSConnectionPool {
     // 설정 업데이트 브로드캐스트
     pub async fn broadcast_settings_update(&self, settings: serde_json::Value) {
         let message = WSMessage::SettingsUpdated { settings };
-        if let Err(e) = self.broadcast_tx.send(message) {
+        if let Err(e) = self.publish(message) {
             log::warn!("[WSPool] Failed to broadcast settings update: {}", e);
         } else {
             let client_count = self.client_count().await;
@@ -197,6 +613,80 @@ impl WSConnectionPool {
         }
     }
     
+    // 세션 만료 브로드캐스트
+    pub async fn broadcast_session_expired(&self, reason: &str) {
+        let message = WSMessage::SessionExpired { reason: reason.to_string() };
+        if let Err(e) = self.publish(message) {
+            log::warn!("[WSPool] Failed to broadcast session expired: {}", e);
+        } else {
+            let client_count = self.client_count().await;
+            log::info!("[WSPool] Broadcasted session expired to {} clients", client_count);
+        }
+    }
+    
+    // 세션 복구 브로드캐스트
+    pub async fn broadcast_session_restored(&self) {
+        if let Err(e) = self.publish(WSMessage::SessionRestored) {
+            log::warn!("[WSPool] Failed to broadcast session restored: {}", e);
+        } else {
+            let client_count = self.client_count().await;
+            log::info!("[WSPool] Broadcasted session restored to {} clients", client_count);
+        }
+    }
+    
+    pub async fn broadcast_monitor_status(&self, status: crate::monitor::MonitorStatus) {
+        if let Err(e) = self.publish(WSMessage::MonitorStatus { status }) {
+            log::warn!("[WSPool] Failed to broadcast monitor status: {}", e);
+        } else {
+            let client_count = self.client_count().await;
+            log::info!("[WSPool] Broadcasted monitor status to {} clients", client_count);
+        }
+    }
+    
+    pub async fn broadcast_recent_followers(&self, followers: Vec<crate::chzzk::FollowerItem>) {
+        if let Err(e) = self.publish(WSMessage::RecentFollowers { followers }) {
+            log::warn!("[WSPool] Failed to broadcast recent followers: {}", e);
+        } else {
+            let client_count = self.client_count().await;
+            log::info!("[WSPool] Broadcasted recent followers to {} clients", client_count);
+        }
+    }
+    
+    pub async fn broadcast_goal_progress(&self, goal: Option<crate::goal::GoalProgress>) {
+        if let Err(e) = self.publish(WSMessage::GoalProgress { goal }) {
+            log::warn!("[WSPool] Failed to broadcast goal progress: {}", e);
+        } else {
+            let client_count = self.client_count().await;
+            log::info!("[WSPool] Broadcasted goal progress to {} clients", client_count);
+        }
+    }
+    
+    pub async fn broadcast_stats(&self, stats: crate::stats::FollowerStats) {
+        if let Err(e) = self.publish(WSMessage::StatsUpdated { stats }) {
+            log::warn!("[WSPool] Failed to broadcast stats: {}", e);
+        } else {
+            log::debug!("[WSPool] Broadcasted stats");
+        }
+    }
+
+    pub async fn broadcast_alert(&self, alert: crate::alert_queue::Alert) {
+        if let Err(e) = self.publish(WSMessage::Alert { alert }) {
+            log::warn!("[WSPool] Failed to broadcast alert: {}", e);
+        } else {
+            let subscriber_count = self.subscriber_count("alerts").await;
+            log::info!("[WSPool] Broadcasted alert to {} subscribers", subscriber_count);
+        }
+    }
+    
+    pub async fn broadcast_alert_skipped(&self, id: u64) {
+        if let Err(e) = self.publish(WSMessage::AlertSkipped { id }) {
+            log::warn!("[WSPool] Failed to broadcast alert skipped: {}", e);
+        } else {
+            let client_count = self.client_count().await;
+            log::info!("[WSPool] Broadcasted alert skipped to {} clients", client_count);
+        }
+    }
+    
     // 정기 정리 태스크 시작
     pub fn start_cleanup_task(&self) {
         let pool = self.clone();
@@ -213,83 +703,114 @@ impl WSConnectionPool {
     }
     
     // 브로드캐스트 채널 구독
-    pub fn subscribe(&self) -> broadcast::Receiver<WSMessage> {
+    pub fn subscribe(&self) -> broadcast::Receiver<WSEvent> {
         self.broadcast_tx.subscribe()
     }
+    
+    // 이 서버 실행의 순번 공간 (hello의 epoch)
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+    
+    // 브로드캐스트 채널 구독과 그 시점의 마지막 순번 (이후 이벤트는 모두 수신기로 들어옴)
+    pub fn subscribe_with_seq(&self) -> (u64, broadcast::Receiver<WSEvent>) {
+        let replay = self.replay.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        (replay.last_seq, self.broadcast_tx.subscribe())
+    }
 }
 
 // 기존 WSManager를 WSConnectionPool로 대체
 pub type WSManager = WSConnectionPool;
 
+// WebSocket 연결 옵션 (`?resume=1`: 연결 직후 resume을 보낼 테니 재전송이 끝날 때까지 실시간 이벤트를 잡아 둠)
+#[derive(Debug, Default, Deserialize)]
+pub struct WebSocketParams {
+    resume: Option<String>,
+}
+
+impl WebSocketParams {
+    fn wants_resume(&self) -> bool {
+        matches!(self.resume.as_deref(), Some("1" | "true"))
+    }
+}
+
 // WebSocket 핸들러
 pub async fn websocket_handler(
     ws: WebSocketUpgrade,
+    Query(params): Query<WebSocketParams>,
     State(state): State<crate::server::ServerState>,
 ) -> axum::response::Response {
     log::info!("[WebSocket] New connection attempt received");
     println!("[WebSocket] WebSocket upgrade request received");
     println!("[WebSocket] Headers: {:?}", ws);
-    let ws_manager = state.ws_manager.clone();
     ws.on_upgrade(move |socket| {
         println!("[WebSocket] WebSocket upgrade successful, handling socket");
-        handle_socket(socket, ws_manager)
+        handle_socket(socket, state, params.wants_resume())
     })
 }
 
-async fn handle_socket(socket: WebSocket, ws_manager: WSConnectionPool) {
+// 새 클라이언트에 보낼 현재 상태
+fn hello_message(state: &crate::server::ServerState, client_id: &str, last_seq: u64) -> WSMessage {
+    let app_state = &state.app_state;
+
+    WSMessage::Hello {
+        protocol_version: WS_PROTOCOL_VERSION,
+        client_id: client_id.to_string(),
+        settings: crate::server::read_settings(&state.app_handle),
+        logged_in: app_state.login_status.lock().map(|s| *s).unwrap_or(false),
+        nickname: app_state.nickname.lock().ok().and_then(|n| n.clone()),
+        monitor: app_state.monitor.status(),
+        recent_followers: app_state.recent_followers(HELLO_RECENT_FOLLOWERS),
+        goal: app_state
+            .goal
+            .lock()
+            .ok()
+            .and_then(|goal| goal.as_ref().map(crate::goal::FollowGoal::progress)),
+        last_seq,
+        epoch: state.ws_manager.epoch(),
+    }
+}
+
+async fn handle_socket(socket: WebSocket, state: crate::server::ServerState, resume: bool) {
+    let ws_manager = state.ws_manager.clone();
     let client_id = Uuid::new_v4().to_string();
     log::info!("[WebSocket] Client {} connecting", client_id);
     
-    let (mut sender, mut receiver) = socket.split();
+    let (sender, mut receiver) = socket.split();
     
-    // 클라이언트 생성 및 풀에 추가
+    // 글로벌 브로드캐스트 구독 (connect_seq 이후 이벤트는 실시간으로, 이전 이벤트는 resume으로 받음)
+    let (connect_seq, global_rx) = ws_manager.subscribe_with_seq();
+    
+    // 클라이언트 생성 및 풀에 추가 (개별 전송 채널은 풀에 넣기 전에 구독)
     let client = WSClient::new(client_id.clone());
+    let client_rx = client.sender.subscribe();
     if let Err(e) = ws_manager.add_client(client).await {
         log::warn!("[WebSocket] Failed to add client {}: {}", client_id, e);
         return;
     }
     
-    // 글로벌 브로드캐스트 구독
-    let mut global_rx = ws_manager.subscribe();
+    // 새로 연결된 오버레이가 다음 이벤트를 기다리지 않고 바로 그릴 수 있도록 현재 상태 전송
+    let hello = hello_message(&state, &client_id, connect_seq);
+    if let Err(e) = ws_manager.send_to(&client_id, hello).await {
+        log::warn!("[WebSocket] Failed to send hello to {}: {}", client_id, e);
+    }
     
     // 메시지 전송 태스크
-    let client_id_clone = client_id.clone();
-    let ws_manager_clone = ws_manager.clone();
-    let send_task = tokio::spawn(async move {
-        loop {
-            match global_rx.recv().await {
-                Ok(ws_msg) => {
-                    let json = match serde_json::to_string(&ws_msg) {
-                        Ok(json) => json,
-                        Err(e) => {
-                            log::error!("[WebSocket] Failed to serialize message: {}", e);
-                            continue;
-                        }
-                    };
-                    
-                    if sender.send(Message::Text(json)).await.is_err() {
-                        log::info!("[WebSocket] Client {} disconnected (send failed)", client_id_clone);
-                        break;
-                    }
-                    
-                    // 활동 업데이트
-                    ws_manager_clone.update_client_activity(&client_id_clone).await;
-                }
-                Err(broadcast::error::RecvError::Closed) => {
-                    log::info!("[WebSocket] Broadcast channel closed");
-                    break;
-                }
-                Err(broadcast::error::RecvError::Lagged(_)) => {
-                    log::warn!("[WebSocket] Client {} lagged behind", client_id_clone);
-                    // 계속 진행
-                }
-            }
-        }
-    });
+    let send_task = tokio::spawn(send_events(
+        sender,
+        client_id.clone(),
+        ws_manager.clone(),
+        Arc::clone(&state.app_state),
+        connect_seq,
+        resume,
+        global_rx,
+        client_rx,
+    ));
     
     // 메시지 수신 태스크
     let client_id_clone = client_id.clone();
     let ws_manager_clone = ws_manager.clone();
+    let app_state = Arc::clone(&state.app_state);
     let recv_task = tokio::spawn(async move {
         while let Some(msg) = receiver.next().await {
             match msg {
@@ -298,8 +819,12 @@ async fn handle_socket(socket: WebSocket, ws_manager: WSConnectionPool) {
                     ws_manager_clone.update_client_activity(&client_id_clone).await;
                     
                     // 클라이언트 메시지 처리
-                    if let Err(e) = handle_client_message(&text, &client_id_clone, &ws_manager_clone).await {
+                    if let Err(e) = handle_client_message(&text, &client_id_clone, &ws_manager_clone, &app_state, connect_seq).await {
                         log::error!("[WebSocket] Error handling message from {}: {}", client_id_clone, e);
+                        let error = WSMessage::Error { message: e.to_string() };
+                        if let Err(e) = ws_manager_clone.send_to(&client_id_clone, error).await {
+                            log::warn!("[WebSocket] {}", e);
+                        }
                     }
                 }
                 Ok(Message::Close(_)) => {
@@ -338,24 +863,208 @@ async fn handle_socket(socket: WebSocket, ws_manager: WSConnectionPool) {
     ws_manager.remove_client(&client_id).await;
 }
 
+// 전송 태스크가 보낼 이벤트 (구독 토픽으로 거를지, 버퍼에서 다시 보내는지)
+struct Outgoing {
+    event: WSEvent,
+    filter_topics: bool,
+    replaying: bool,
+}
+
+// 재연결한 클라이언트에 연결 전에 놓친 이벤트(resume 재전송)가 연결 뒤 실시간 이벤트보다 먼저 가도록,
+// resume 처리가 끝나거나 대기 시간이 지날 때까지 실시간 이벤트를 잡아 둠 (요청한 클라이언트만)
+struct ResumeGate {
+    held: Option<Vec<Outgoing>>,
+    deadline: tokio::time::Instant,
+}
+
+impl ResumeGate {
+    fn new(hold: bool, deadline: tokio::time::Instant) -> Self {
+        Self {
+            held: hold.then(Vec::new),
+            deadline,
+        }
+    }
+
+    fn is_holding(&self) -> bool {
+        self.held.is_some()
+    }
+
+    // 열려 있으면 그대로 돌려주고, 닫혀 있으면 잡아 둠
+    fn pass(&mut self, outgoing: Vec<Outgoing>) -> Vec<Outgoing> {
+        match self.held.as_mut() {
+            Some(held) => {
+                held.extend(outgoing);
+                Vec::new()
+            }
+            None => outgoing,
+        }
+    }
+
+    // 잡아 둔 이벤트를 돌려주고 이후로는 바로 통과
+    fn open(&mut self) -> Vec<Outgoing> {
+        self.held.take().unwrap_or_default()
+    }
+}
+
+// 한 클라이언트에 개별 메시지와 브로드캐스트 이벤트를 순서대로 전송 (연결이 끊기거나 채널이 닫히면 종료)
+#[allow(clippy::too_many_arguments)]
+async fn send_events<S>(
+    mut sender: S,
+    client_id: String,
+    ws_manager: WSManager,
+    app_state: Arc<crate::state::AppState>,
+    connect_seq: u64,
+    resume: bool,
+    mut global_rx: broadcast::Receiver<WSEvent>,
+    mut client_rx: broadcast::Receiver<WSEvent>,
+) where
+    S: futures_util::Sink<Message> + Unpin,
+{
+    // 이 클라이언트가 처리한 마지막 브로드캐스트 순번
+    let mut last_seq = connect_seq;
+    let mut gate = ResumeGate::new(resume, tokio::time::Instant::now() + RESUME_WAIT);
+    
+    'send: loop {
+        let outgoing = tokio::select! {
+            // hello와 resume 재전송이 다른 이벤트보다 먼저 가도록 개별 메시지를 우선 처리
+            biased;
+            
+            // 이 클라이언트에만 보내는 메시지 (hello, pong, 구독 확인, 재전송, 오류 등)
+            message = client_rx.recv() => match message {
+                Ok(event) => {
+                    // resumed는 재전송이 끝난 뒤 오므로, 그 뒤에 잡아 둔 실시간 이벤트를 이어서 보냄
+                    let resumed = matches!(event.message, WSMessage::Resumed { .. });
+                    let mut outgoing = vec![Outgoing { event, filter_topics: false, replaying: false }];
+                    if resumed {
+                        outgoing.extend(gate.open());
+                    }
+                    outgoing
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    log::warn!("[WebSocket] Client {} dropped {} direct messages", client_id, skipped);
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            // resume을 요청하고도 보내지 않는 클라이언트는 대기 시간이 지나면 그대로 전달
+            _ = tokio::time::sleep_until(gate.deadline), if gate.is_holding() => gate.open(),
+            message = global_rx.recv() => match message {
+                Ok(event) => {
+                    let seq = event.seq.unwrap_or_default();
+                    // 밀림 복구 때 버퍼에서 이미 처리한 이벤트
+                    if seq <= last_seq {
+                        continue;
+                    }
+                    last_seq = seq;
+                    gate.pass(vec![Outgoing { event, filter_topics: true, replaying: false }])
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    // 수신기에서 밀려난 이벤트를 재전송 버퍼에서 채움
+                    let (events, complete) = ws_manager.replay_between(last_seq, u64::MAX);
+                    log::warn!(
+                        "[WebSocket] Client {} lagged behind by {} events, replaying {} from buffer",
+                        client_id,
+                        skipped,
+                        events.len()
+                    );
+                    if !complete {
+                        log::warn!("[WebSocket] Some events for client {} were already dropped from the replay buffer", client_id);
+                    }
+                    if let Some(seq) = events.last().and_then(|event| event.seq) {
+                        last_seq = seq;
+                    }
+                    gate.pass(
+                        events
+                            .into_iter()
+                            .map(|event| Outgoing { event, filter_topics: true, replaying: true })
+                            .collect(),
+                    )
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    log::info!("[WebSocket] Broadcast channel closed");
+                    break;
+                }
+            },
+        };
+        
+        for Outgoing { event, filter_topics, replaying } in outgoing {
+            // 구독하지 않은 토픽은 건너뜀
+            if filter_topics && !ws_manager.should_deliver(&client_id, &event.message).await {
+                continue;
+            }
+            // 밀림 복구로 다시 보내는 끝난 알림은 건너뜀
+            if replaying && !event.message.replayable(&app_state.alerts) {
+                continue;
+            }
+            
+            let json = match serde_json::to_string(&event) {
+                Ok(json) => json,
+                Err(e) => {
+                    log::error!("[WebSocket] Failed to serialize message: {}", e);
+                    continue;
+                }
+            };
+            
+            if sender.send(Message::Text(json)).await.is_err() {
+                log::info!("[WebSocket] Client {} disconnected (send failed)", client_id);
+                break 'send;
+            }
+            
+            // 활동 업데이트
+            ws_manager.update_client_activity(&client_id).await;
+        }
+    }
+}
+
 async fn handle_client_message(
     text: &str,
     client_id: &str,
     ws_manager: &WSConnectionPool,
+    app_state: &crate::state::AppState,
+    connect_seq: u64,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let message: WSMessage = serde_json::from_str(text)?;
     
     match message {
         WSMessage::Ping => {
             log::debug!("[WebSocket] Ping from client {}", client_id);
-            // Pong 응답은 자동으로 처리됨
+            ws_manager.send_to(client_id, WSMessage::Pong).await?;
         }
         WSMessage::Subscribe { topics } => {
-            log::info!("[WebSocket] Client {} subscribed to: {:?}", client_id, topics);
-            // 구독 정보는 클라이언트 생성 시 기본값으로 설정됨
+            let (known, unknown) = resolve_topics(&topics);
+            if let Some(topics) = ws_manager.update_subscriptions(client_id, |client| client.subscribe(known)).await {
+                log::info!("[WebSocket] Client {} subscribed to: {:?}", client_id, topics);
+                ws_manager.send_to(client_id, WSMessage::Subscribed { topics, unknown }).await?;
+            }
+        }
+        WSMessage::Unsubscribe { topics } => {
+            let (known, unknown) = resolve_topics(&topics);
+            if let Some(topics) = ws_manager.update_subscriptions(client_id, |client| client.unsubscribe(&known)).await {
+                log::info!("[WebSocket] Client {} unsubscribed, remaining: {:?}", client_id, topics);
+                ws_manager.send_to(client_id, WSMessage::Subscribed { topics, unknown }).await?;
+            }
         }
-        WSMessage::TestFollower => {
-            log::info!("[WebSocket] Test follower request from client {}", client_id);
+        WSMessage::Resume { last_seq } => {
+            // 연결 이후 이벤트는 실시간으로 받고 있으므로 연결 전까지 놓친 이벤트만 재전송
+            let (events, complete) = ws_manager.replay_between(last_seq, connect_seq);
+            let mut replayed = 0;
+            for event in events {
+                if event.message.replayable(&app_state.alerts) && ws_manager.should_deliver(client_id, &event.message).await {
+                    ws_manager.send_event_to(client_id, event).await?;
+                    replayed += 1;
+                }
+            }
+            log::info!(
+                "[WebSocket] Client {} resumed from seq {} (replayed: {}, complete: {})",
+                client_id,
+                last_seq,
+                replayed,
+                complete
+            );
+            ws_manager.send_to(client_id, WSMessage::Resumed { replayed, complete }).await?;
+        }
+        WSMessage::TestFollower { local } => {
+            log::info!("[WebSocket] Test follower request from client {} (local: {})", client_id, local);
             
             // 테스트 팔로워 생성
             let now = std::time::SystemTime::now()
@@ -372,8 +1081,26 @@ async fn handle_client_message(
                 following_since: chrono::Utc::now().to_rfc3339(),
             };
             
-            // 테스트 알림 브로드캐스트
-            ws_manager.broadcast_test_notification(test_follower).await;
+            // 요청한 클라이언트에만 보내거나 알림 큐에 추가 (다른 알림과 겹치지 않게)
+            if local {
+                ws_manager
+                    .send_to(client_id, WSMessage::TestNotification { follower: test_follower })
+                    .await?;
+            } else {
+                app_state.alerts.enqueue(test_follower, None, false, true);
+            }
+        }
+        WSMessage::AlertDone { id } => {
+            // alert를 받지 않는 클라이언트(대시보드 등)의 응답으로 알림이 끝나지 않도록 구독자만 허용
+            if !ws_manager.is_client_subscribed(client_id, "alerts").await {
+                log::debug!("[WebSocket] Ignoring alert_done from client {} (not subscribed to alerts)", client_id);
+            } else if app_state.alerts.done(id) {
+                log::debug!("[WebSocket] Alert #{} done (client {})", id, client_id);
+            }
+        }
+        WSMessage::AlertControl { action, id } => {
+            let status = crate::alert_queue::control(app_state, ws_manager, action, id).await?;
+            ws_manager.send_to(client_id, WSMessage::AlertQueue { status }).await?;
         }
         _ => {
             log::debug!("[WebSocket] Unhandled message from client {}: {:?}", client_id, message);
@@ -381,4 +1108,257 @@ async fn handle_client_message(
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chzzk::test_support::follower;
+
+    fn topics(names: &[&str]) -> Vec<String> {
+        names.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn first_subscribe_replaces_default_topics() {
+        let mut client = WSClient::new("a".to_string());
+        assert!(client.is_subscribed("settings"));
+
+        client.subscribe(topics(&["followers"]));
+        client.subscribe(topics(&["test"]));
+
+        assert_eq!(client.subscriptions, topics(&["followers", "test"]));
+        assert!(!client.is_subscribed("settings"));
+
+        client.unsubscribe(&topics(&["test"]));
+        assert_eq!(client.subscriptions, topics(&["followers"]));
+    }
+
+    #[tokio::test]
+    async fn send_to_reaches_only_the_target_client() {
+        let pool = WSConnectionPool::new();
+        let a = WSClient::new("a".to_string());
+        let b = WSClient::new("b".to_string());
+        let mut a_rx = a.sender.subscribe();
+        let mut b_rx = b.sender.subscribe();
+        pool.add_client(a).await.unwrap();
+        pool.add_client(b).await.unwrap();
+
+        pool.send_to("a", WSMessage::Pong).await.unwrap();
+
+        assert!(matches!(a_rx.try_recv(), Ok(WSEvent { seq: None, message: WSMessage::Pong })));
+        assert!(b_rx.try_recv().is_err());
+        assert!(pool.send_to("missing", WSMessage::Pong).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn counts_only_alert_subscribers() {
+        let pool = WSConnectionPool::new();
+        let overlay = WSClient::new("overlay".to_string());
+        let mut dashboard = WSClient::new("dashboard".to_string());
+        dashboard.subscribe(topics(&["followers"]));
+        pool.add_client(overlay).await.unwrap();
+        pool.add_client(dashboard).await.unwrap();
+
+        assert_eq!(pool.client_count().await, 2);
+        assert_eq!(pool.subscriber_count("alerts").await, 1);
+        assert!(pool.is_client_subscribed("overlay", "alerts").await);
+        assert!(!pool.is_client_subscribed("dashboard", "alerts").await);
+        assert!(!pool.is_client_subscribed("missing", "alerts").await);
+    }
+
+    #[test]
+    fn replay_buffer_keeps_latest_events_in_order() {
+        let mut buffer = ReplayBuffer::new(3);
+        for _ in 0..5 {
+            buffer.push(WSMessage::SessionRestored);
+        }
+
+        let seqs = |events: Vec<WSEvent>| events.into_iter().filter_map(|e| e.seq).collect::<Vec<_>>();
+        let (events, complete) = buffer.between(2, u64::MAX);
+        assert_eq!(seqs(events), vec![3, 4, 5]);
+        assert!(complete);
+
+        // 1, 2는 이미 밀려남
+        let (events, complete) = buffer.between(0, 4);
+        assert_eq!(seqs(events), vec![3, 4]);
+        assert!(!complete);
+
+        // 서버 재시작 등으로 알 수 없는 순번
+        assert!(!buffer.between(9, u64::MAX).1);
+        assert!(buffer.between(5, 5).1);
+    }
+
+    #[test]
+    fn event_serializes_sequence_alongside_message() {
+        let event = WSEvent { seq: Some(7), message: WSMessage::SessionExpired { reason: "만료".to_string() } };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json, serde_json::json!({ "seq": 7, "type": "session_expired", "reason": "만료" }));
+
+        let direct = serde_json::to_value(WSEvent { seq: None, message: WSMessage::Pong }).unwrap();
+        assert_eq!(direct, serde_json::json!({ "type": "pong" }));
+
+        let resume: WSMessage = serde_json::from_str(r#"{"type":"resume","last_seq":3}"#).unwrap();
+        assert!(matches!(resume, WSMessage::Resume { last_seq: 3 }));
+    }
+
+    #[tokio::test]
+    async fn resume_replays_only_the_alert_still_on_screen() {
+        let pool = WSConnectionPool::new();
+        let app_state = crate::state::AppState::default();
+        // 수신기가 없으면 브로드캐스트가 실패하므로 하나 열어 둠
+        let _listener = pool.subscribe_with_seq();
+        let done = app_state.alerts.enqueue(follower("a", "a"), None, false, false).unwrap();
+        let current = app_state.alerts.enqueue(follower("b", "b"), None, false, false).unwrap();
+
+        // a는 표시가 끝났고 b는 완료 응답 전
+        pool.broadcast_alert(app_state.alerts.take_next().unwrap()).await;
+        assert!(app_state.alerts.done(done));
+        pool.broadcast_alert(app_state.alerts.take_next().unwrap()).await;
+        pool.publish(WSMessage::SessionRestored).unwrap();
+
+        let client = WSClient::new("a".to_string());
+        let mut rx = client.sender.subscribe();
+        pool.add_client(client).await.unwrap();
+        let (connect_seq, _global_rx) = pool.subscribe_with_seq();
+
+        handle_client_message(r#"{"type":"resume","lastSeq":0}"#, "a", &pool, &app_state, connect_seq)
+            .await
+            .unwrap();
+
+        let mut received = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            received.push(event.message);
+        }
+        assert_eq!(received.len(), 3);
+        assert!(matches!(&received[0], WSMessage::Alert { alert } if alert.id == current));
+        assert!(matches!(received[1], WSMessage::SessionRestored));
+        assert!(matches!(received[2], WSMessage::Resumed { replayed: 2, complete: true }));
+
+        // 표시가 끝나면 더 이상 다시 보내지 않음
+        assert!(app_state.alerts.done(current));
+        let (events, _) = pool.replay_between(0, u64::MAX);
+        assert!(events
+            .iter()
+            .filter(|event| event.message.replayable(&app_state.alerts))
+            .all(|event| !matches!(event.message, WSMessage::Alert { .. })));
+    }
+
+    #[test]
+    fn resolves_wildcard_and_unknown_topics() {
+        let (known, unknown) = resolve_topics(&topics(&["*", "chat"]));
+
+        assert_eq!(known, topics(&WS_TOPICS));
+        assert_eq!(unknown, topics(&["chat"]));
+
+        // 언팔로우는 이름을 지정해야만 구독되고, 기본 구독에도 없음
+        assert_eq!(resolve_topics(&topics(&["unfollows"])).0, topics(&["unfollows"]));
+        assert!(!WSClient::new("overlay".to_string()).is_subscribed("unfollows"));
+        let lost = WSMessage::FollowerLost {
+            follower: crate::chzzk::FollowerItem::default(),
+            followed_for_secs: None,
+            unfollowed_at: String::new(),
+        };
+        assert_eq!(lost.topic(), Some("unfollows"));
+        assert_eq!(WSMessage::SessionRestored.topic(), Some("system"));
+        assert!(WSClient::new("dashboard".to_string()).is_subscribed("stats"));
+        assert_eq!(WSMessage::Pong.topic(), None);
+    }
+
+    #[tokio::test]
+    async fn default_overlay_gets_watched_unfollow_but_not_follower_lost() {
+        let pool = WSConnectionPool::new();
+        pool.add_client(WSClient::new("overlay".to_string())).await.unwrap();
+
+        let watched = WSMessage::WatchedUnfollow {
+            follower: crate::chzzk::FollowerItem::default(),
+            alert: crate::watchlist::WatchAlert::default(),
+        };
+        let lost = WSMessage::FollowerLost {
+            follower: crate::chzzk::FollowerItem::default(),
+            followed_for_secs: None,
+            unfollowed_at: String::new(),
+        };
+
+        assert!(pool.should_deliver("overlay", &watched).await);
+        assert!(!pool.should_deliver("overlay", &lost).await);
+    }
+
+    #[tokio::test]
+    async fn resume_replay_is_sent_before_live_events() {
+        let pool = WSConnectionPool::new();
+        let app_state = Arc::new(crate::state::AppState::default());
+        let goal = || WSMessage::GoalProgress { goal: None };
+
+        // 연결 전에 놓친 이벤트 1, 2
+        let _ = pool.publish(goal());
+        let _ = pool.publish(goal());
+        let (connect_seq, global_rx) = pool.subscribe_with_seq();
+        let client = WSClient::new("overlay".to_string());
+        let client_rx = client.sender.subscribe();
+        pool.add_client(client).await.unwrap();
+
+        // resume 요청보다 먼저 도착한 실시간 이벤트 3
+        let _ = pool.publish(goal());
+        let sending = tokio::spawn({
+            let pool = pool.clone();
+            let app_state = Arc::clone(&app_state);
+            async move {
+                let mut sent: Vec<Message> = Vec::new();
+                send_events(&mut sent, "overlay".to_string(), pool, app_state, connect_seq, true, global_rx, client_rx).await;
+                sent
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        handle_client_message(r#"{"type":"resume","lastSeq":0}"#, "overlay", &pool, &app_state, connect_seq)
+            .await
+            .unwrap();
+        let _ = pool.publish(goal());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        pool.remove_client("overlay").await;
+
+        let sent: Vec<serde_json::Value> = sending
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|message| match message {
+                Message::Text(text) => serde_json::from_str(&text).unwrap(),
+                other => panic!("unexpected message: {:?}", other),
+            })
+            .collect();
+        let order: Vec<String> = sent
+            .iter()
+            .map(|event| match event["seq"].as_u64() {
+                Some(seq) => seq.to_string(),
+                None => event["type"].as_str().unwrap().to_string(),
+            })
+            .collect();
+        assert_eq!(order, ["1", "2", "resumed", "3", "4"]);
+    }
+
+    #[tokio::test]
+    async fn live_events_are_not_held_without_resume_request() {
+        let pool = WSConnectionPool::new();
+        let app_state = Arc::new(crate::state::AppState::default());
+        let (connect_seq, global_rx) = pool.subscribe_with_seq();
+        let client = WSClient::new("overlay".to_string());
+        let client_rx = client.sender.subscribe();
+        pool.add_client(client).await.unwrap();
+
+        let sending = tokio::spawn({
+            let pool = pool.clone();
+            async move {
+                let mut sent: Vec<Message> = Vec::new();
+                send_events(&mut sent, "overlay".to_string(), pool, app_state, connect_seq, false, global_rx, client_rx).await;
+                sent
+            }
+        });
+        // resume을 요청하지 않았으므로 대기 시간(RESUME_WAIT)을 기다리지 않고 바로 전달
+        let _ = pool.publish(WSMessage::GoalProgress { goal: None });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        pool.remove_client("overlay").await;
+
+        let sent = sending.await.unwrap();
+        assert_eq!(sent.len(), 1);
+    }
+}
@@ -0,0 +1,334 @@
+//! 목 치지직 서버(`fazzk-mock-chzzk`)에 실제 팔로워 모니터(감독 태스크와 폴링 작업)를 연결해
+//! 팔로우 폭주, 세션 만료 후 재개, 요청 한도 초과, 연속 에러 후 재시작을
+//! WebSocket 이벤트와 모니터 상태로 확인합니다.
+
+use fazzk_tauri_lib::baseline::OfflineFollowMode;
+use fazzk_tauri_lib::chzzk::{ChzzkClient, ChzzkConfig};
+use fazzk_tauri_lib::follow_history::CooldownPolicy;
+use fazzk_tauri_lib::goal::FollowGoal;
+use fazzk_tauri_lib::monitor::{self, MonitorCommand, MonitorHost, MonitorState, MonitorStatus};
+use fazzk_tauri_lib::state::{AppState, CookieData};
+use fazzk_tauri_lib::watchlist::WatchList;
+use fazzk_tauri_lib::websocket::{WSClient, WSConnectionPool, WSEvent, WSMessage};
+use serde_json::json;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
+
+/// 목 서버의 채널 userIdHash
+const CHANNEL_ID: &str = "mock_channel";
+
+/// 테스트용 폴링 주기 (에러 백오프도 이 값에서 시작)
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// 상태 변경이나 이벤트를 기다리는 최대 시간
+const WAIT_TIMEOUT: Duration = Duration::from_secs(15);
+
+// 테스트마다 빈 포트로 띄우고, 끝나면 종료하는 목 서버
+struct MockServer {
+    child: Child,
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl MockServer {
+    async fn start() -> Self {
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .expect("빈 포트 찾기")
+            .port();
+        let child = Command::new(env!("CARGO_BIN_EXE_fazzk-mock-chzzk"))
+            .args(["--port", &port.to_string()])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("목 서버 실행");
+        let server = Self {
+            child,
+            base_url: format!("http://127.0.0.1:{}", port),
+            http: reqwest::Client::new(),
+        };
+
+        for _ in 0..100 {
+            if server.http.get(server.url("/mock/state")).send().await.is_ok() {
+                return server;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        panic!("목 서버가 시작되지 않았습니다");
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    async fn post(&self, path: &str, body: serde_json::Value) {
+        self.http
+            .post(self.url(path))
+            .json(&body)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .expect("목 서버 조작 요청");
+    }
+
+    // 치지직 API 주소를 목 서버로 바꾸고 로그인한 앱 상태
+    fn app_state(&self) -> AppState {
+        let config = ChzzkConfig {
+            api_base_url: self.base_url.clone(),
+            game_api_base_url: self.base_url.clone(),
+        };
+        let state = AppState::with_chzzk_api(Arc::new(ChzzkClient::new(reqwest::Client::new(), config)));
+        *state.cookies.lock().unwrap() = Some(CookieData {
+            nid_aut: "mock_aut".to_string(),
+            nid_ses: "mock_ses".to_string(),
+        });
+        *state.user_id_hash.lock().unwrap() = Some(CHANNEL_ID.to_string());
+        *state.login_status.lock().unwrap() = true;
+        // 폴링마다 목 서버에 요청하도록 응답 캐시를 끔
+        state.api_cache.lock().unwrap().cache_duration = Duration::ZERO;
+        state
+    }
+
+    // 오버레이 하나가 연결된 상태로 모니터를 시작하고 첫 기준점을 잡을 때까지 대기
+    async fn start_monitor(&self) -> RunningMonitor {
+        let state = Arc::new(self.app_state());
+        let ws_manager = WSConnectionPool::new();
+        ws_manager.add_client(WSClient::new("overlay".to_string())).await.unwrap();
+        let host = TestHost::default();
+
+        let mut monitor = RunningMonitor {
+            events: ws_manager.subscribe(),
+            status: state.monitor.subscribe(),
+            pending: Vec::new(),
+            emitted: Arc::clone(&host.emitted),
+            state: Arc::clone(&state),
+        };
+        monitor::spawn_monitor(state, ws_manager, host);
+        monitor.wait_status(|s| s.state == MonitorState::Running).await;
+        monitor
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+// AppHandle 대신 쓰는 모니터 호스트 (짧은 폴링 주기, 기본 설정, 저장 파일 없음, 앱 이벤트는 이름만 기록)
+#[derive(Default)]
+struct TestHost {
+    emitted: Arc<Mutex<Vec<String>>>,
+}
+
+impl MonitorHost for TestHost {
+    fn polling_interval(&self) -> Duration {
+        POLL_INTERVAL
+    }
+
+    fn watch_list(&self) -> WatchList {
+        WatchList::default()
+    }
+
+    fn unfollow_events_enabled(&self) -> bool {
+        false
+    }
+
+    fn cooldown_policy(&self) -> CooldownPolicy {
+        CooldownPolicy::default()
+    }
+
+    fn offline_follow_mode(&self) -> OfflineFollowMode {
+        OfflineFollowMode::default()
+    }
+
+    fn baseline_path(&self) -> Option<PathBuf> {
+        None
+    }
+
+    fn save_goal(&self, _goal: &FollowGoal) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn write_text_outputs(&self, _app_state: &AppState) {}
+
+    fn emit(&self, event: &str, _payload: serde_json::Value) -> Result<(), String> {
+        self.emitted.lock().unwrap().push(event.to_string());
+        Ok(())
+    }
+}
+
+// 실행 중인 모니터와 그 출력 (WebSocket 브로드캐스트, 상태, 앱 이벤트)
+struct RunningMonitor {
+    state: Arc<AppState>,
+    events: broadcast::Receiver<WSEvent>,
+    status: watch::Receiver<MonitorStatus>,
+    // 먼저 받았지만 아직 확인하지 않은 메시지 (상태 브로드캐스트와 다른 이벤트의 순서는 정해져 있지 않음)
+    pending: Vec<WSMessage>,
+    emitted: Arc<Mutex<Vec<String>>>,
+}
+
+impl RunningMonitor {
+    // 조건에 맞는 상태가 될 때까지 대기
+    async fn wait_status(&mut self, condition: impl FnMut(&MonitorStatus) -> bool) -> MonitorStatus {
+        tokio::time::timeout(WAIT_TIMEOUT, self.status.wait_for(condition))
+            .await
+            .expect("모니터 상태 대기 시간 초과")
+            .expect("모니터 상태 채널")
+            .clone()
+    }
+
+    // 조건에 맞는 WebSocket 메시지가 올 때까지 대기 (맞지 않는 메시지는 다음 확인을 위해 보관)
+    async fn next_message<T>(&mut self, mut pick: impl FnMut(&WSMessage) -> Option<T>) -> T {
+        let found = self
+            .pending
+            .iter()
+            .enumerate()
+            .find_map(|(index, message)| pick(message).map(|value| (index, value)));
+        if let Some((index, value)) = found {
+            self.pending.remove(index);
+            return value;
+        }
+
+        let receive = async {
+            loop {
+                let event = self.events.recv().await.expect("브로드캐스트 수신");
+                if let Some(value) = pick(&event.message) {
+                    return value;
+                }
+                self.pending.push(event.message);
+            }
+        };
+        tokio::time::timeout(WAIT_TIMEOUT, receive)
+            .await
+            .expect("WebSocket 메시지 대기 시간 초과")
+    }
+
+    // 조건에 맞는 상태가 WebSocket monitor_status로 전달될 때까지 대기
+    async fn next_status_message(&mut self, mut condition: impl FnMut(&MonitorStatus) -> bool) -> MonitorStatus {
+        self.next_message(|message| match message {
+            WSMessage::MonitorStatus { status } if condition(status) => Some(status.clone()),
+            _ => None,
+        })
+        .await
+    }
+
+    fn emitted(&self) -> Vec<String> {
+        self.emitted.lock().unwrap().clone()
+    }
+}
+
+#[tokio::test]
+async fn follow_storm_is_announced_in_follow_order() {
+    let server = MockServer::start().await;
+    let mut monitor = server.start_monitor().await;
+
+    // 한 페이지(10명)보다 많은 팔로우가 폴링 사이에 몰림
+    server.post("/mock/followers/burst", json!({ "count": 30 })).await;
+
+    let mut last_followed_at = None;
+    for _ in 0..30 {
+        let (follower, is_refollow, silent) = monitor
+            .next_message(|message| match message {
+                WSMessage::NewFollower { follower, is_refollow, silent, .. } => {
+                    Some((follower.clone(), *is_refollow, *silent))
+                }
+                _ => None,
+            })
+            .await;
+        assert!(!is_refollow && !silent, "{:?}", follower);
+        // 팔로우한 순서(오래된 것 먼저)로 전달
+        let followed_at = follower.followed_at();
+        assert!(followed_at > last_followed_at);
+        last_followed_at = followed_at;
+    }
+    assert_eq!(*monitor.state.session_follower_count.lock().unwrap(), 30);
+}
+
+#[tokio::test]
+async fn unauthorized_suspends_monitor_until_session_is_renewed() {
+    let server = MockServer::start().await;
+    let mut monitor = server.start_monitor().await;
+
+    server.post("/mock/fault", json!({ "mode": "unauthorized", "count": 1 })).await;
+
+    // 세션 만료: 로그인 해제 후 재로그인 대기
+    let status = monitor.wait_status(|s| s.state == MonitorState::Suspended).await;
+    assert_eq!(status.reason.as_deref(), Some("session expired"));
+    monitor.next_status_message(|s| s.state == MonitorState::Suspended).await;
+    let reason = monitor
+        .next_message(|message| match message {
+            WSMessage::SessionExpired { reason } => Some(reason.clone()),
+            _ => None,
+        })
+        .await;
+    assert_eq!(reason, "unauthorized");
+    assert!(!*monitor.state.login_status.lock().unwrap());
+    assert_eq!(monitor.emitted(), ["session-expired"]);
+
+    // /auth/cookies와 같이 새 쿠키로 로그인 상태를 복구하고 모니터를 깨움
+    *monitor.state.login_status.lock().unwrap() = true;
+    monitor.state.session_renewed.notify_one();
+
+    monitor
+        .next_message(|message| matches!(message, WSMessage::SessionRestored).then_some(()))
+        .await;
+    let status = monitor.wait_status(|s| s.state == MonitorState::Running).await;
+    assert_eq!(status.restarts, 0);
+    monitor.next_status_message(|s| s.state == MonitorState::Running).await;
+}
+
+#[tokio::test]
+async fn rate_limit_backs_off_for_retry_after_without_counting_errors() {
+    let server = MockServer::start().await;
+    let mut monitor = server.start_monitor().await;
+
+    // 목 서버는 Retry-After: 1로 응답
+    server.post("/mock/fault", json!({ "mode": "rate_limited", "count": 1 })).await;
+
+    let status = monitor.wait_status(|s| s.state == MonitorState::Backoff).await;
+    assert_eq!(status.reason.as_deref(), Some("rate limited"));
+    assert_eq!(status.retry_after_secs, Some(1));
+    assert_eq!(status.error_count, 0);
+    monitor
+        .next_status_message(|s| s.state == MonitorState::Backoff && s.retry_after_secs == Some(1))
+        .await;
+
+    // 대기가 끝나면 다시 폴링
+    let status = monitor.wait_status(|s| s.state == MonitorState::Running).await;
+    assert_eq!(status.retry_after_secs, None);
+    assert_eq!(status.error_count, 0);
+}
+
+#[tokio::test]
+async fn too_many_errors_restart_the_worker() {
+    let server = MockServer::start().await;
+    let mut monitor = server.start_monitor().await;
+
+    // 해제할 때까지 잘못된 JSON 응답
+    server.post("/mock/fault", json!({ "mode": "malformed" })).await;
+
+    let status = monitor
+        .wait_status(|s| s.reason.as_deref() == Some("too many errors, restarting"))
+        .await;
+    assert_eq!(status.state, MonitorState::Backoff);
+    // MAX_ERRORS
+    assert_eq!(status.error_count, 10);
+    assert_eq!(status.restarts, 0);
+    monitor
+        .next_status_message(|s| s.reason.as_deref() == Some("too many errors, restarting"))
+        .await;
+
+    // 재시작 대기(1분)를 기다리지 않고 재시작 명령으로 바로 다시 시작
+    server.post("/mock/fault", json!({ "mode": "none" })).await;
+    monitor.state.monitor.send(MonitorCommand::Restart).unwrap();
+
+    let status = monitor
+        .wait_status(|s| s.state == MonitorState::Running && s.restarts == 1)
+        .await;
+    assert_eq!(status.error_count, 0);
+}
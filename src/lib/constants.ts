@@ -58,12 +58,6 @@ export const POLLING_CONFIG = {
   COOLDOWN: 1000,
 } as const;
 
-// 루블리스 설정
-export const RUBLIS_CONFIG = {
-  USER_HASH: 'f2f551b67556276caa1f590604a7d92a',
-  COOLDOWN_SECONDS: 5,
-} as const;
-
 // OBS 설정
 export const OBS_CONFIG = {
   RECOMMENDED_SIZE_VERTICAL: '300x350',
@@ -2,7 +2,13 @@
  * 중앙화된 설정 관리 시스템
  */
 
-import type { AppSettings, AnimationType, NotificationLayout } from '../types/common';
+import type {
+  AppSettings,
+  AnimationType,
+  NotificationLayout,
+  OfflineFollowMode,
+  RefollowCooldownMode
+} from '../types/common';
 
 // 설정 기본값 정의
 export const DEFAULT_SETTINGS: AppSettings = {
@@ -14,7 +20,16 @@ export const DEFAULT_SETTINGS: AppSettings = {
   animationType: 'fade' as AnimationType,
   notificationLayout: 'vertical' as NotificationLayout,
   textColor: '#ffffff',
-  textSize: 100
+  textSize: 100,
+  offlineFollowMode: 'announce' as OfflineFollowMode,
+  watchList: [],
+  enableUnfollowEvents: false,
+  refollowCooldown: 600,
+  refollowCooldownMode: 'silent' as RefollowCooldownMode,
+  textOutputEnabled: false,
+  textOutputFolder: '',
+  textOutputRecentCount: 5,
+  textOutputTemplates: null
 };
 
 // 설정 키 상수
@@ -31,6 +46,10 @@ export const SETTINGS_VALIDATION = {
   textSize: { min: 50, max: 200, step: 10 },
   animationType: ['fade', 'slide-up', 'slide-down', 'bounce'],
   notificationLayout: ['vertical', 'horizontal'],
+  offlineFollowMode: ['announce', 'absorb'],
+  refollowCooldownMode: ['silent', 'suppress'],
+  refollowCooldown: { min: 0, max: 86400, step: 1 },
+  textOutputRecentCount: { min: 1, max: 50, step: 1 },
   textColor: /^#[0-9A-Fa-f]{6}$/
 } as const;
 
@@ -308,6 +327,8 @@ export class SettingsManager {
               parsedValue = parseInt(paramValue);
               break;
             case 'enableTTS':
+            case 'enableUnfollowEvents':
+            case 'textOutputEnabled':
               parsedValue = paramValue === 'true';
               break;
             default:
@@ -399,6 +420,8 @@ export class SettingsManager {
       case 'pollingInterval':
       case 'displayDuration':
       case 'textSize':
+      case 'refollowCooldown':
+      case 'textOutputRecentCount':
         const numValidation = validation as { min: number; max: number; step: number };
         return typeof value === 'number' && 
                value >= numValidation.min && 
@@ -406,6 +429,8 @@ export class SettingsManager {
 
       case 'animationType':
       case 'notificationLayout':
+      case 'offlineFollowMode':
+      case 'refollowCooldownMode':
         const arrayValidation = validation as readonly string[];
         return arrayValidation.includes(value);
 
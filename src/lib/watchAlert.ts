@@ -0,0 +1,71 @@
+/**
+ * 관심 팔로워(watchList) 전용 알림 문구/사운드 처리
+ */
+
+import type { WatchEntry } from '../types/common';
+import { api } from './api';
+
+// 알림에 함께 오는 전용 옵션 (서버의 WatchAlert)
+export interface WatchAlert {
+  sound?: string;
+  template?: string;
+}
+
+// 알림 큐 항목 중 문구/사운드에 필요한 부분
+export interface AlertItem {
+  user?: { nickname?: string };
+  alert?: WatchAlert;
+  isUnfollow?: boolean;
+}
+
+/**
+ * 전용 문구의 `{nickname}`을 닉네임으로 치환
+ */
+export function renderAlertTemplate(template: string, nickname: string): string {
+  return template.split('{nickname}').join(nickname);
+}
+
+/**
+ * 닉네임 아래에 표시할 문구 (전용 문구가 있으면 전체 문구를 대신함)
+ */
+export function alertMessage(item: AlertItem): string {
+  const template = item.alert?.template;
+  if (template) {
+    return renderAlertTemplate(template, item.user?.nickname ?? '');
+  }
+  return item.isUnfollow ? '님이 언팔로우했습니다.' : '님이 팔로우했습니다!';
+}
+
+/**
+ * TTS로 읽을 문구
+ */
+export function alertSpeech(item: AlertItem): string {
+  const nickname = item.user?.nickname ?? '';
+  const template = item.alert?.template;
+  if (template) {
+    return renderAlertTemplate(template, nickname);
+  }
+  return item.isUnfollow ? `${nickname}님이 언팔로우했습니다.` : `${nickname}님이 팔로우했습니다.`;
+}
+
+/**
+ * 전용 사운드 경로를 오디오 src로 변환 (웹 주소는 그대로, 앱에서는 로컬 파일 변환)
+ */
+export function alertSoundSrc(sound: string): string {
+  if (/^(https?:|data:|blob:)/.test(sound) || !api.isTauri) {
+    return sound;
+  }
+  return api.convertFileSrc(sound);
+}
+
+/**
+ * 팔로워와 일치하는 관심 팔로워 항목 (해시가 있으면 해시로, 없으면 닉네임으로 비교)
+ */
+export function findWatchEntry(
+  watchList: WatchEntry[] | undefined,
+  user: { userIdHash?: string; nickname?: string }
+): WatchEntry | undefined {
+  return (watchList ?? []).find(entry =>
+    entry.userIdHash ? entry.userIdHash === user.userIdHash : entry.nickname === user.nickname
+  );
+}
@@ -2,19 +2,77 @@
 interface WebSocketMessage {
   type:
     | 'pong'
+    | 'hello'
     | 'new_follower'
+    | 'alert'
+    | 'alert_skipped'
+    | 'alert_queue'
+    | 'alert_done'
+    | 'alert_control'
+    | 'watched_unfollow'
+    | 'unfollow'
     | 'test_notification'
     | 'settings_updated'
+    | 'goal_progress'
+    | 'stats_updated'
+    | 'recent_followers'
     | 'error'
     | 'subscribe'
+    | 'unsubscribe'
+    | 'subscribed'
+    | 'resume'
+    | 'resumed'
     | 'ping'
     | 'test_follower';
   follower?: Follower;
+  alert?: any; // new_follower/watched_unfollow: WatchAlert, alert: QueuedAlert
+  isRefollow?: boolean;
+  silent?: boolean;
+  followedForSecs?: number | null;
+  unfollowedAt?: string;
   settings?: any;
+  goal?: GoalProgress | null;
+  stats?: any; // stats_updated: GET /stats의 content
+  followers?: Follower[];
   message?: string;
   topics?: string[];
+  unknown?: string[];
+  local?: boolean;
+  protocolVersion?: number;
+  clientId?: string;
+  loggedIn?: boolean;
+  nickname?: string | null;
+  monitor?: any;
+  recentFollowers?: Follower[];
+  seq?: number;
+  lastSeq?: number;
+  epoch?: number;
+  id?: number;
+  action?: AlertAction;
+  status?: AlertQueueStatus;
+  replayed?: number;
+  complete?: boolean;
 }
 
+// 연결 직후 서버가 보내는 현재 상태
+export interface HelloSnapshot {
+  protocolVersion: number;
+  clientId: string;
+  settings: any;
+  loggedIn: boolean;
+  nickname: string | null;
+  monitor: any;
+  recentFollowers: Follower[];
+  goal: GoalProgress | null;
+  lastSeq: number;
+  epoch: number;
+}
+
+// 구독할 수 있는 토픽 (서버의 WS_TOPICS와 동일)
+// 'unfollows'는 방송 화면에 나가지 않도록 ALL_TOPICS에서 빠져 있으며 직접 지정해야 받음
+export type WSTopic = 'followers' | 'alerts' | 'test' | 'settings' | 'goal' | 'stats' | 'system' | 'unfollows';
+export const ALL_TOPICS: WSTopic[] = ['followers', 'alerts', 'test', 'settings', 'goal', 'stats', 'system'];
+
 // 팔로워 타입 정의
 interface Follower {
   user?: {
@@ -25,14 +83,63 @@ interface Follower {
   followed_at?: string;
 }
 
+// 관심 팔로워 전용 알림 옵션 (설정의 watchList)
+interface WatchAlert {
+  sound?: string;
+  template?: string;
+}
+
+// 팔로우 목표 진행 상황 (GET /goal, goal_progress 메시지)
+export interface GoalProgress {
+  label: string;
+  target: number;
+  startCount: number;
+  currentCount: number;
+  startedAt: string;
+  gained: number;
+  remaining: number;
+  percent: number;
+  reached: boolean;
+}
+
+// 서버 알림 큐에서 표시할 차례가 된 알림 (표시가 끝나면 alertDone(id) 호출)
+export interface QueuedAlert {
+  id: number;
+  follower: Follower;
+  alert?: WatchAlert;
+  isRefollow: boolean;
+  isTest: boolean;
+  replay: boolean;
+  queuedAt: string;
+}
+
+// 알림 큐 상태 (GET /alerts, alert_control 응답)
+export interface AlertQueueStatus {
+  paused: boolean;
+  current: QueuedAlert | null;
+  pending: QueuedAlert[];
+  recent: QueuedAlert[];
+}
+
+export type AlertAction = 'pause' | 'resume' | 'skip' | 'replay';
+
 // 이벤트 핸들러 타입
 type EventHandler<T = any> = (data?: T) => void;
 
 // 이벤트 타입 매핑
 interface EventHandlers {
   new_follower: EventHandler<Follower>[];
+  alert: EventHandler<QueuedAlert>[];
+  alert_skipped: EventHandler<number>[];
+  alert_queue: EventHandler<AlertQueueStatus>[];
+  watched_unfollow: EventHandler<Follower>[];
+  unfollow: EventHandler<any>[];
   test_notification: EventHandler<Follower>[];
   settings_updated: EventHandler<any>[];
+  goal_progress: EventHandler<GoalProgress | null>[];
+  stats_updated: EventHandler<any>[];
+  hello: EventHandler<HelloSnapshot>[];
+  recent_followers: EventHandler<Follower[]>[];
   connected: EventHandler<void>[];
   disconnected: EventHandler<void>[];
   error: EventHandler<Error>[];
@@ -66,6 +173,7 @@ const log = createLogger('WebSocket');
 // WebSocket 클라이언트 관리
 export class WSClient {
   private baseUrl: string;
+  private topics: WSTopic[];
   private ws: WebSocket | null = null;
   private reconnectAttempts: number = 0;
   private readonly maxReconnectAttempts: number = 10;
@@ -75,6 +183,10 @@ export class WSClient {
   private lastError: string | null = null;
   private lastConnected: number | null = null;
   private connectionTimeout: NodeJS.Timeout | null = null;
+  // 마지막으로 받은 브로드캐스트 이벤트 순번 (재연결 시 놓친 이벤트 요청에 사용)
+  private lastSeq: number = 0;
+  // lastSeq가 속한 서버 실행 (서버가 다시 시작되면 바뀜)
+  private epoch: number | null = null;
   private pingInterval: NodeJS.Timeout | null = null;
   private readonly connectionTimeoutMs = 10000; // 10초
   private readonly pingIntervalMs = 30000; // 30초
@@ -82,16 +194,27 @@ export class WSClient {
   // 이벤트 핸들러들
   private eventHandlers: EventHandlers = {
     new_follower: [],
+    alert: [],
+    alert_skipped: [],
+    alert_queue: [],
+    watched_unfollow: [],
+    unfollow: [],
     test_notification: [],
     settings_updated: [],
+    goal_progress: [],
+    stats_updated: [],
+    hello: [],
+    recent_followers: [],
     connected: [],
     disconnected: [],
     error: [],
     reconnecting: [],
   };
 
-  constructor(baseUrl: string) {
+  // topics: 받을 토픽 (OBS 오버레이는 필요한 토픽만 지정)
+  constructor(baseUrl: string, topics: WSTopic[] = ALL_TOPICS) {
     this.baseUrl = baseUrl.replace('http', 'ws');
+    this.topics = topics;
     log.info('Client initialized with URL:', this.baseUrl);
 
     // 연결 관리자 이벤트 리스너 설정
@@ -223,7 +346,8 @@ export class WSClient {
     }
 
     this.isConnecting = true;
-    const wsUrl = `${this.baseUrl}/ws`;
+    // resume=1: hello 뒤에 resume을 보낼 테니 재전송이 끝날 때까지 실시간 이벤트를 잡아 두도록 요청
+    const wsUrl = `${this.baseUrl}/ws?resume=1`;
     const connectionId = crypto.randomUUID();
 
     log.info('Connecting to:', wsUrl);
@@ -255,8 +379,8 @@ export class WSClient {
         // 핑 인터벌 시작
         this.startPingInterval();
 
-        // 팔로워 토픽 구독
-        const subscribed = this.subscribe(['followers']);
+        // 지정한 토픽 구독
+        const subscribed = this.subscribe(this.topics);
         if (!subscribed) {
           log.warn('Failed to subscribe to topics after connection');
         }
@@ -268,6 +392,9 @@ export class WSClient {
       this.ws.onmessage = (event: MessageEvent) => {
         try {
           const message: WebSocketMessage = JSON.parse(event.data);
+          if (typeof message.seq === 'number' && message.seq > this.lastSeq) {
+            this.lastSeq = message.seq;
+          }
           this.handleMessage(message);
         } catch (e) {
           const error = this.createWSError(
@@ -335,9 +462,75 @@ export class WSClient {
           connectionManager.onPong();
           break;
 
+        case 'hello':
+          log.info('Hello received (protocol v' + message.protocolVersion + ')');
+          this.resumeFrom(message.lastSeq ?? 0, message.epoch ?? 0);
+          this.emit('hello', {
+            protocolVersion: message.protocolVersion ?? 0,
+            clientId: message.clientId ?? '',
+            settings: message.settings,
+            loggedIn: message.loggedIn ?? false,
+            nickname: message.nickname ?? null,
+            monitor: message.monitor,
+            recentFollowers: message.recentFollowers ?? [],
+            goal: message.goal ?? null,
+            lastSeq: message.lastSeq ?? 0,
+            epoch: message.epoch ?? 0,
+          });
+          break;
+
+        case 'resumed':
+          log.info('Resumed, replayed events:', message.replayed);
+          if (message.complete === false) {
+            log.warn('Some events were missed during disconnect and could not be replayed');
+          }
+          break;
+
+        case 'subscribed':
+          log.info('Subscribed topics:', message.topics);
+          if (message.unknown?.length) {
+            log.warn('Unknown topics ignored:', message.unknown);
+          }
+          break;
+
         case 'new_follower':
           log.info('New follower received:', message.follower?.user?.nickname);
-          this.emit('new_follower', message.follower);
+          // 관심 팔로워 alert 옵션과 재팔로우 여부를 팔로워 객체에 붙여 전달
+          this.emit('new_follower', {
+            ...message.follower,
+            ...(message.alert ? { alert: message.alert } : {}),
+            isRefollow: message.isRefollow ?? false,
+            silent: message.silent ?? false,
+          });
+          break;
+
+        case 'alert':
+          log.info('Alert received:', message.alert?.id);
+          this.emit('alert', message.alert);
+          break;
+
+        case 'alert_skipped':
+          log.info('Alert skipped:', message.id);
+          this.emit('alert_skipped', message.id);
+          break;
+
+        case 'alert_queue':
+          log.debug('Alert queue status:', message.status);
+          this.emit('alert_queue', message.status);
+          break;
+
+        case 'watched_unfollow':
+          log.info('Watched follower unfollowed:', message.follower?.user?.nickname);
+          this.emit('watched_unfollow', { ...message.follower, alert: message.alert });
+          break;
+
+        case 'unfollow':
+          log.info('Follower lost:', message.follower?.user?.nickname);
+          this.emit('unfollow', {
+            follower: message.follower,
+            followedForSecs: message.followedForSecs ?? null,
+            unfollowedAt: message.unfollowedAt,
+          });
           break;
 
         case 'test_notification':
@@ -350,6 +543,21 @@ export class WSClient {
           this.emit('settings_updated', message.settings);
           break;
 
+        case 'goal_progress':
+          log.info('Goal progress updated:', message.goal?.currentCount);
+          this.emit('goal_progress', message.goal ?? null);
+          break;
+
+        case 'stats_updated':
+          log.debug('Stats updated:', message.stats?.totals);
+          this.emit('stats_updated', message.stats);
+          break;
+
+        case 'recent_followers':
+          log.debug('Recent followers updated:', message.followers?.length);
+          this.emit('recent_followers', message.followers ?? []);
+          break;
+
         case 'error':
           const serverError = this.createWSError(
             message.message || 'Unknown server error',
@@ -391,14 +599,47 @@ export class WSClient {
     }
   }
 
+  // 재연결이면 끊긴 동안 놓친 이벤트 요청 (serverSeq: 서버의 연결 시점 순번, epoch: 서버 실행 구분 값)
+  private resumeFrom(serverSeq: number, epoch: number): void {
+    const sameServer = this.epoch === epoch;
+    this.epoch = epoch;
+
+    if (sameServer && this.lastSeq > 0 && this.lastSeq < serverSeq) {
+      log.info(`Requesting missed events (${this.lastSeq} -> ${serverSeq})`);
+      this.send({
+        type: 'resume',
+        lastSeq: this.lastSeq,
+      });
+      return;
+    }
+
+    if (!sameServer || this.lastSeq === 0) {
+      // 첫 연결이거나 서버가 다시 시작되어 이전 순번과 비교할 수 없음
+      this.lastSeq = serverSeq;
+    }
+    // resume=1로 연결했으므로 놓친 이벤트가 없어도 resume을 보내야 서버가 잡아 둔 실시간 이벤트를 바로 보냄
+    this.send({
+      type: 'resume',
+      lastSeq: serverSeq,
+    });
+  }
+
   // 구독
-  subscribe(topics: string[]): boolean {
+  subscribe(topics: WSTopic[]): boolean {
     return this.send({
       type: 'subscribe',
       topics: topics,
     });
   }
 
+  // 구독 해제
+  unsubscribe(topics: WSTopic[]): boolean {
+    return this.send({
+      type: 'unsubscribe',
+      topics: topics,
+    });
+  }
+
   // 핑
   ping(): boolean {
     return this.send({
@@ -406,10 +647,28 @@ export class WSClient {
     });
   }
 
-  // 테스트 팔로워 요청
-  requestTestFollower(): boolean {
+  // 테스트 팔로워 요청 (local이면 이 클라이언트에만 테스트 알림 전송)
+  requestTestFollower(local: boolean = false): boolean {
     return this.send({
       type: 'test_follower',
+      local,
+    });
+  }
+
+  // 알림 표시가 끝났음을 서버 알림 큐에 알림
+  alertDone(id: number): boolean {
+    return this.send({
+      type: 'alert_done',
+      id,
+    });
+  }
+
+  // 알림 큐 제어 (replay는 id가 없으면 마지막 알림), 응답은 alert_queue 이벤트
+  controlAlerts(action: AlertAction, id?: number): boolean {
+    return this.send({
+      type: 'alert_control',
+      action,
+      ...(id !== undefined ? { id } : {}),
     });
   }
 
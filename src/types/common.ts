@@ -13,10 +13,43 @@ export interface AppSettings {
   notificationLayout: NotificationLayout;
   textColor: string;
   textSize: number;
+  offlineFollowMode: OfflineFollowMode;
+  watchList: WatchEntry[];
+  enableUnfollowEvents: boolean;
+  refollowCooldown: number;
+  refollowCooldownMode: RefollowCooldownMode;
+  textOutputEnabled: boolean;
+  textOutputFolder: string;
+  textOutputRecentCount: number;
+  textOutputTemplates: TextOutputTemplates | null;
 }
 
 export type AnimationType = 'fade' | 'slide-up' | 'slide-down' | 'bounce';
 export type NotificationLayout = 'vertical' | 'horizontal';
+// 앱이 꺼져 있는 동안 들어온 팔로우 처리: 알림으로 표시 / 조용히 기준점에 반영
+export type OfflineFollowMode = 'announce' | 'absorb';
+// 재팔로우 쿨다운 중 처리: 알림 없는 이벤트로 전달 / 무시
+export type RefollowCooldownMode = 'silent' | 'suppress';
+
+// OBS 텍스트 소스용 파일 문구 (비어 있는 항목은 기본값 사용)
+export interface TextOutputTemplates {
+  latestFollower?: string;
+  recentFollower?: string;
+  recentSeparator?: string;
+  sessionFollowerCount?: string;
+  goal?: string;
+}
+
+// 관심 팔로워 (userIdHash 또는 nickname 중 하나는 필수)
+export interface WatchEntry {
+  userIdHash?: string;
+  nickname?: string;
+  alertOnFollow?: boolean;
+  alertOnUnfollow?: boolean;
+  sound?: string;
+  template?: string;
+  bypassCooldown?: boolean;
+}
 
 // 팔로워 관련 타입
 export interface FollowerItem {